@@ -0,0 +1,556 @@
+//! Source code generated from a built dataset. Code enums of `src/codes.rs` come
+//! from `bubbles codegen`, the countries as TypeScript, Python or Rust for other
+//! projects from `bubbles export`.
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write};
+use serde_json::Value;
+
+use crate::Country;
+
+/// Source of `CountryCode` and `CountryCodeA3` enums of the codes found from the
+/// countries and `CurrencyCode` of the ISO 4217 list bundled in
+/// `data/currency_codes.json`. Codes that can't be enum variants are left out.
+pub fn codes_source<K: Display>(countries: &BTreeMap<K, Country>) -> String {
+    let mut a2 = BTreeMap::new();
+
+    for c in countries.values() {
+        if is_code(&c.iso_3166_1_a2, 2) {
+            a2.insert(c.iso_3166_1_a2.to_owned(), c.iso_3166_1_a3.as_ref().map(|s|s.to_string()));
+        }
+    }
+
+    let a3 = a2.iter()
+        .filter_map(|(k, v)|v.as_ref().map(|s|(s.to_owned(), k.to_owned())))
+        .collect::<BTreeMap<String, String>>();
+
+    // Currencies come from the bundled ISO 4217 list rather than the countries,
+    // a build that lost a currency doesn't drop its code. Withdrawn currencies
+    // keep their codes as strings.
+    let currencies = crate::currency::iso_4217().keys()
+        .filter(|s|is_code(s, 3))
+        .cloned()
+        .collect::<Vec<String>>();
+
+    let mut s = String::new();
+
+    s.push_str("// Generated with `bubbles codegen` from the built countries, do not edit.\n\n");
+    s.push_str("use std::fmt::{Display, Formatter};\n");
+    s.push_str("use std::str::FromStr;\n");
+    s.push_str("use serde::{Deserialize, Serialize};\n\n");
+    s.push_str("use crate::error::Error;\n");
+
+    let codes = a2.keys().cloned().collect::<Vec<_>>();
+    code_enum(&mut s, "CountryCode", "ISO 3166-1 alpha-2 code of a country", "country", &codes);
+
+    let _ = writeln!(s, "\nimpl CountryCode {{");
+    let _ = writeln!(s, "    pub fn alpha3(&self) -> Option<CountryCodeA3> {{");
+    let _ = writeln!(s, "        match self {{");
+
+    for (k, v) in a2.iter() {
+        match v {
+            Some(c) => { let _ = writeln!(s, "            Self::{} => Some(CountryCodeA3::{}),", k, c); },
+            None => { let _ = writeln!(s, "            Self::{} => None,", k); },
+        }
+    }
+
+    s.push_str("        }\n    }\n}\n");
+
+    let codes = a3.keys().cloned().collect::<Vec<_>>();
+    code_enum(&mut s, "CountryCodeA3", "ISO 3166-1 alpha-3 code of a country", "country", &codes);
+
+    let _ = writeln!(s, "\nimpl CountryCodeA3 {{");
+    let _ = writeln!(s, "    pub fn alpha2(&self) -> CountryCode {{");
+    let _ = writeln!(s, "        match self {{");
+
+    for (k, v) in a3.iter() {
+        let _ = writeln!(s, "            Self::{} => CountryCode::{},", k, v);
+    }
+
+    s.push_str("        }\n    }\n}\n\n");
+    s.push_str("impl From<CountryCodeA3> for CountryCode {\n");
+    s.push_str("    fn from(code: CountryCodeA3) -> Self {\n        code.alpha2()\n    }\n}\n");
+
+    code_enum(&mut s, "CurrencyCode", "ISO 4217 code of a currency in use",
+        "currency", &currencies);
+
+    s
+}
+
+/// Enum of the codes with `as_str`, `ALL`, `Display` and case insensitive `FromStr`
+fn code_enum(s: &mut String, name: &str, doc: &str, kind: &str, codes: &[String]) {
+    let _ = writeln!(s, "\n/// {}", doc);
+    let _ = writeln!(s, "#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]");
+    let _ = writeln!(s, "pub enum {} {{", name);
+
+    for c in codes {
+        let _ = writeln!(s, "    {},", c);
+    }
+
+    let _ = writeln!(s, "}}\n");
+    let _ = writeln!(s, "impl {} {{", name);
+    let _ = writeln!(s, "    pub const ALL: [Self; {}] = [", codes.len());
+
+    for c in codes {
+        let _ = writeln!(s, "        Self::{},", c);
+    }
+
+    let _ = writeln!(s, "    ];\n");
+    let _ = writeln!(s, "    pub fn as_str(&self) -> &'static str {{");
+    let _ = writeln!(s, "        match self {{");
+
+    for c in codes {
+        let _ = writeln!(s, "            Self::{} => \"{}\",", c, c);
+    }
+
+    let _ = writeln!(s, "        }}\n    }}\n}}\n");
+    let _ = writeln!(s, "impl Display for {} {{", name);
+    let _ = writeln!(s, "    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {{");
+    let _ = writeln!(s, "        write!(f, \"{{}}\", self.as_str())\n    }}\n}}\n");
+    let _ = writeln!(s, "impl FromStr for {} {{", name);
+    let _ = writeln!(s, "    type Err = Error;\n");
+    let _ = writeln!(s, "    fn from_str(s: &str) -> Result<Self, Self::Err> {{");
+    let _ = writeln!(s, "        match s.trim().to_ascii_uppercase().as_str() {{");
+
+    for c in codes {
+        let _ = writeln!(s, "            \"{}\" => Ok(Self::{}),", c, c);
+    }
+
+    let _ = writeln!(s, "            _ => Err(Error::UnknownCode {{ kind: \"{}\".into(), code: s.to_string() }}),", kind);
+    let _ = writeln!(s, "        }}\n    }}\n}}");
+}
+
+fn is_code(s: &str, len: usize) -> bool {
+    s.len() == len && s.chars().all(|c|c.is_ascii_uppercase())
+}
+
+/// Types of the exported countries, kept in step with the model
+enum Type {
+    Str,
+    /// Integer with its Rust type
+    Int(&'static str),
+    Bool,
+    Opt(&'static Type),
+    List(&'static Type),
+    Struct(&'static str),
+    /// Enum written with its serialized variant names
+    Enum(&'static str),
+}
+
+const ENUMS: &[(&str, &[&str])] = &[
+    ("SymbolKind", &["primary", "narrow", "plural"]),
+    ("LanguageStatus", &["official", "co_official", "recognized", "widely_spoken"]),
+];
+
+/// Structs in the order they can be declared, fields in the order of the model
+const STRUCTS: &[(&str, &[(&str, Type)])] = &[
+    ("Symbol", &[("symbol", Type::Str), ("kind", Type::Enum("SymbolKind"))]),
+    ("Currency", &[
+        ("name", Type::Str),
+        ("iso_4217", Type::Opt(&Type::Str)),
+        ("numeric_code", Type::Opt(&Type::Int("u16"))),
+        ("minor_unit", Type::Opt(&Type::Int("u8"))),
+        ("symbol", Type::Opt(&Type::Str)),
+        ("symbols", Type::Opt(&Type::List(&Type::Struct("Symbol")))),
+        ("fraction", Type::Opt(&Type::Str)),
+        ("fractions_in_unit", Type::Opt(&Type::Int("i32"))),
+        ("withdrawn", Type::Opt(&Type::Str)),
+    ]),
+    ("Time", &[("zone", Type::Str), ("dst", Type::Opt(&Type::Str))]),
+    ("Endonym", &[
+        ("name", Type::Str),
+        ("lang", Type::Opt(&Type::Str)),
+        ("script", Type::Opt(&Type::Str)),
+        ("romanized", Type::Bool),
+    ]),
+    ("Language", &[
+        ("name", Type::Str),
+        ("iso_639_1", Type::Opt(&Type::Str)),
+        ("iso_639_3", Type::Opt(&Type::Str)),
+        ("status", Type::Opt(&Type::Enum("LanguageStatus"))),
+    ]),
+    ("Script", &[("code", Type::Str), ("name", Type::Str), ("rtl", Type::Bool)]),
+    ("Country", &[
+        ("exonym", Type::Str),
+        ("name", Type::Str),
+        ("aliases", Type::Opt(&Type::List(&Type::Str))),
+        ("capital", Type::Opt(&Type::Str)),
+        ("capital_endonyms", Type::Opt(&Type::List(&Type::Struct("Endonym")))),
+        ("iso_3166_1_a2", Type::Str),
+        ("iso_3166_1_a3", Type::Opt(&Type::Str)),
+        ("iso_3166_1_numeric", Type::Opt(&Type::Int("u16"))),
+        ("tld", Type::Opt(&Type::Str)),
+        ("flag", Type::Opt(&Type::Str)),
+        ("currency", Type::Opt(&Type::Struct("Currency"))),
+        ("historical_currencies", Type::Opt(&Type::List(&Type::Struct("Currency")))),
+        ("time", Type::Opt(&Type::Struct("Time"))),
+        ("calling_code", Type::Opt(&Type::Str)),
+        ("endonyms", Type::Opt(&Type::List(&Type::Struct("Endonym")))),
+        ("languages", Type::Opt(&Type::List(&Type::Struct("Language")))),
+        ("scripts", Type::Opt(&Type::List(&Type::Struct("Script")))),
+        ("locales", Type::Opt(&Type::List(&Type::Str))),
+    ]),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Lang {
+    TypeScript,
+    Python,
+    Rust,
+}
+
+/// TypeScript module with the types of the model and `countries` keyed by the
+/// alpha-2 codes
+pub fn typescript_source<K: Display>(countries: &BTreeMap<K, Country>) -> String {
+    let mut s = String::new();
+
+    s.push_str("// Generated with `bubbles export typescript` from the built countries, do not edit.\n");
+
+    for (name, variants) in ENUMS {
+        let variants = variants.iter().map(|v|format!("\"{}\"", v)).collect::<Vec<_>>();
+        let _ = writeln!(s, "\nexport type {} = {};", name, variants.join(" | "));
+    }
+
+    for (name, fields) in STRUCTS {
+        let _ = writeln!(s, "\nexport interface {} {{", name);
+
+        for (f, t) in fields.iter() {
+            let _ = writeln!(s, "  {}: {};", f, type_name(Lang::TypeScript, t));
+        }
+
+        s.push_str("}\n");
+    }
+
+    s.push_str("\n/** Countries by their ISO 3166-1 alpha-2 codes */\n");
+    s.push_str("export const countries: Readonly<Record<string, Country>> = {\n");
+
+    for (k, v) in country_values(countries) {
+        let _ = writeln!(s, "  \"{}\": {{\n    {},\n  }},", k,
+            fields(Lang::TypeScript, "Country", &v).join(",\n    "));
+    }
+
+    s.push_str("};\n");
+
+    s
+}
+
+/// Python module with frozen dataclasses of the model and `COUNTRIES` keyed by
+/// the alpha-2 codes
+pub fn python_source<K: Display>(countries: &BTreeMap<K, Country>) -> String {
+    let mut s = String::new();
+
+    s.push_str("# Generated with `bubbles export python` from the built countries, do not edit.\n");
+    s.push_str("from dataclasses import dataclass\n");
+    s.push_str("from typing import Dict, Literal, Optional, Tuple\n");
+
+    for (name, variants) in ENUMS {
+        let variants = variants.iter().map(|v|format!("\"{}\"", v)).collect::<Vec<_>>();
+        let _ = writeln!(s, "\n{} = Literal[{}]", name, variants.join(", "));
+    }
+
+    for (name, fields) in STRUCTS {
+        let _ = writeln!(s, "\n\n@dataclass(frozen=True)\nclass {}:", name);
+
+        for (f, t) in fields.iter() {
+            let _ = writeln!(s, "    {}: {}", f, type_name(Lang::Python, t));
+        }
+    }
+
+    s.push_str("\n\n# Countries by their ISO 3166-1 alpha-2 codes\n");
+    s.push_str("COUNTRIES: Dict[str, Country] = {\n");
+
+    for (k, v) in country_values(countries) {
+        let _ = writeln!(s, "    \"{}\": Country(\n        {},\n    ),", k,
+            fields(Lang::Python, "Country", &v).join(",\n        "));
+    }
+
+    s.push_str("}\n");
+
+    s
+}
+
+/// Rust source with the types of the model and a `COUNTRIES` static, needs no
+/// crates
+pub fn rust_source<K: Display>(countries: &BTreeMap<K, Country>) -> String {
+    let mut s = String::new();
+
+    s.push_str("// Generated with `bubbles export rust` from the built countries, do not edit.\n");
+
+    for (name, variants) in ENUMS {
+        let _ = writeln!(s, "\n#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]");
+        let _ = writeln!(s, "pub enum {} {{", name);
+
+        for v in variants.iter() {
+            let _ = writeln!(s, "    {},", camel_case(v));
+        }
+
+        s.push_str("}\n");
+    }
+
+    for (name, fields) in STRUCTS {
+        let _ = writeln!(s, "\n#[derive(Debug, Clone, Copy)]");
+        let _ = writeln!(s, "pub struct {} {{", name);
+
+        for (f, t) in fields.iter() {
+            let _ = writeln!(s, "    pub {}: {},", f, type_name(Lang::Rust, t));
+        }
+
+        s.push_str("}\n");
+    }
+
+    s.push_str("\n/// Countries ordered by their ISO 3166-1 alpha-2 codes\n");
+    s.push_str("pub static COUNTRIES: &[Country] = &[\n");
+
+    for (_, v) in country_values(countries) {
+        let _ = writeln!(s, "    Country {{\n        {},\n    }},",
+            fields(Lang::Rust, "Country", &v).join(",\n        "));
+    }
+
+    s.push_str("];\n\n");
+    s.push_str("/// Country of the alpha-2 code, in any case\n");
+    s.push_str("pub fn country(alpha2: &str) -> Option<&'static Country> {\n");
+    s.push_str("    COUNTRIES.iter().find(|c| c.iso_3166_1_a2.eq_ignore_ascii_case(alpha2))\n}\n");
+
+    s
+}
+
+/// Countries as JSON values by their alpha-2 codes
+fn country_values<K: Display>(countries: &BTreeMap<K, Country>) -> Vec<(String, Value)> {
+    countries.iter()
+        .map(|(k, c)|(k.to_string(), serde_json::to_value(c).unwrap_or_default()))
+        .collect()
+}
+
+fn type_name(lang: Lang, t: &Type) -> String {
+    match (lang, t) {
+        (Lang::TypeScript, Type::Str) => "string".into(),
+        (Lang::TypeScript, Type::Int(_)) => "number".into(),
+        (Lang::TypeScript, Type::Bool) => "boolean".into(),
+        (Lang::TypeScript, Type::Opt(t)) => format!("{} | null", type_name(lang, t)),
+        (Lang::TypeScript, Type::List(t)) => format!("{}[]", type_name(lang, t)),
+        (Lang::Python, Type::Str) => "str".into(),
+        (Lang::Python, Type::Int(_)) => "int".into(),
+        (Lang::Python, Type::Bool) => "bool".into(),
+        (Lang::Python, Type::Opt(t)) => format!("Optional[{}]", type_name(lang, t)),
+        (Lang::Python, Type::List(t)) => format!("Tuple[{}, ...]", type_name(lang, t)),
+        (Lang::Rust, Type::Str) => "&'static str".into(),
+        (Lang::Rust, Type::Int(t)) => t.to_string(),
+        (Lang::Rust, Type::Bool) => "bool".into(),
+        (Lang::Rust, Type::Opt(t)) => format!("Option<{}>", type_name(lang, t)),
+        (Lang::Rust, Type::List(t)) => format!("&'static [{}]", type_name(lang, t)),
+        (_, Type::Struct(n)) | (_, Type::Enum(n)) => n.to_string(),
+    }
+}
+
+/// Fields of the struct as they are written in a literal of it
+fn fields(lang: Lang, name: &str, v: &Value) -> Vec<String> {
+    let fields = STRUCTS.iter()
+        .find(|(n, _)|n.eq(&name))
+        .map(|(_, f)|*f)
+        .unwrap_or_default();
+
+    fields.iter()
+        .map(|(f, t)|{
+            let x = literal(lang, t, v.get(f).unwrap_or(&Value::Null));
+
+            match lang {
+                Lang::Python => format!("{}={}", f, x),
+                _ => format!("{}: {}", f, x),
+            }
+        })
+        .collect()
+}
+
+/// Value of the type written in the language
+fn literal(lang: Lang, t: &Type, v: &Value) -> String {
+    let string = |s: &str|match lang {
+        Lang::Rust => format!("{:?}", s),
+        _ => serde_json::to_string(s).unwrap_or_default(),
+    };
+
+    match (t, v) {
+        (Type::Opt(_), Value::Null) => match lang {
+            Lang::TypeScript => "null".into(),
+            _ => "None".into(),
+        },
+        (Type::Opt(t), v) => match lang {
+            Lang::Rust => format!("Some({})", literal(lang, t, v)),
+            _ => literal(lang, t, v),
+        },
+        (Type::Str, v) => string(v.as_str().unwrap_or_default()),
+        (Type::Int(_), v) => v.as_i64().unwrap_or_default().to_string(),
+        (Type::Bool, v) => match (lang, v.as_bool().unwrap_or_default()) {
+            (Lang::Python, true) => "True".into(),
+            (Lang::Python, false) => "False".into(),
+            (_, b) => b.to_string(),
+        },
+        (Type::Enum(n), v) => match lang {
+            Lang::Rust => format!("{}::{}", n, camel_case(v.as_str().unwrap_or_default())),
+            _ => string(v.as_str().unwrap_or_default()),
+        },
+        (Type::List(t), v) => {
+            let items = v.as_array().into_iter().flatten()
+                .map(|x|literal(lang, t, x))
+                .collect::<Vec<_>>();
+
+            match lang {
+                Lang::TypeScript => format!("[{}]", items.join(", ")),
+                Lang::Python if items.len() == 1 => format!("({},)", items[0]),
+                Lang::Python => format!("({})", items.join(", ")),
+                Lang::Rust => format!("&[{}]", items.join(", ")),
+            }
+        },
+        (Type::Struct(n), v) => {
+            let fields = fields(lang, n, v).join(", ");
+
+            match lang {
+                Lang::TypeScript => format!("{{ {} }}", fields),
+                Lang::Python => format!("{}({})", n, fields),
+                Lang::Rust => format!("{} {{ {} }}", n, fields),
+            }
+        },
+    }
+}
+
+/// `CoOfficial` of `co_official`
+fn camel_case(s: &str) -> String {
+    s.split('_')
+        .map(|w|{
+            let mut c = w.chars();
+
+            match c.next() {
+                Some(f) => f.to_uppercase().chain(c).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use std::collections::BTreeSet;
+
+    /// Why the value is not of the type, checked all the way down
+    fn mismatch(t: &Type, v: &Value) -> Option<String> {
+        match (t, v) {
+            (Type::Str, Value::String(_)) => None,
+            (Type::Int(_), Value::Number(n)) if n.is_i64() => None,
+            (Type::Bool, Value::Bool(_)) => None,
+            (Type::Opt(_), Value::Null) => None,
+            (Type::Opt(t), v) => mismatch(t, v),
+            (Type::List(t), Value::Array(a)) => a.iter().find_map(|x|mismatch(t, x)),
+            (Type::Enum(n), Value::String(s)) => match ENUMS.iter().find(|(e, _)|e.eq(n)) {
+                Some((_, variants)) if variants.contains(&s.as_str()) => None,
+                _ => Some(format!("{} is not a variant of {}", s, n)),
+            },
+            (Type::Struct(n), Value::Object(o)) => {
+                let fields = STRUCTS.iter().find(|(s, _)|s.eq(n))?.1;
+                let keys = fields.iter().map(|(f, _)|*f).collect::<BTreeSet<_>>();
+
+                if ! keys.iter().copied().eq(o.keys().map(|k|k.as_str())) {
+                    return Some(format!("fields of {} are {:?}, model has {:?}", n, keys,
+                        o.keys().collect::<Vec<_>>()))
+                }
+
+                fields.iter().find_map(|(f, t)|mismatch(t, &o[*f])
+                    .map(|e|format!("{}.{}: {}", n, f, e)))
+            },
+            (_, v) => Some(format!("{} is not of the type", v)),
+        }
+    }
+
+    fn assert_model<T: serde::Serialize>(name: &'static str, value: T) {
+        let v = serde_json::to_value(value).unwrap();
+
+        assert_eq!(mismatch(&Type::Struct(name), &v), None);
+    }
+
+    #[test]
+    fn types_are_in_step_with_the_model() {
+        let symbol = Symbol { symbol: "€".into(), kind: SymbolKind::Primary };
+        let currency = Currency {
+            iso_4217: Some("EUR".into()), numeric_code: Some(978), minor_unit: Some(2),
+            symbol: Some("€".into()), symbols: Some(vec![symbol.to_owned()]),
+            fraction: Some("Cent".into()), fractions_in_unit: Some(100),
+            withdrawn: Some("2002-03".into()), ..Currency::default()
+        };
+        let language = Language {
+            iso_639_1: Some("fi".into()), iso_639_3: Some("fin".into()),
+            status: Some(LanguageStatus::Official), ..Language::new("Finnish")
+        };
+        let time = Time { zone: "UTC+02:00".into(), dst: Some("UTC+03:00".into()) };
+        let country = Country {
+            aliases: Some(vec!["Suomi".into()]), capital: Some("Helsinki".into()),
+            capital_endonyms: Some(vec![Endonym::new("Helsinki", Some("fi"))]),
+            iso_3166_1_a3: Some(Alpha3::new("FIN").unwrap()), iso_3166_1_numeric: Some(246),
+            tld: Some(Tld::new(".fi").unwrap()), flag: Some(Flag::new("🇫🇮").unwrap()),
+            currency: Some(currency.to_owned()),
+            historical_currencies: Some(vec![currency.to_owned()]),
+            time: Some(time.to_owned()),
+            calling_code: Some(CallingCode::new("358").unwrap()),
+            endonyms: Some(vec![Endonym::new("Suomi", Some("fi"))]),
+            languages: Some(vec![language.to_owned()]),
+            scripts: Some(vec![Script::default()]),
+            locales: Some(vec!["fi-FI".into()]),
+            ..Country::default()
+        };
+
+        assert_model("Country", country);
+        assert_model("Country", Country::default());
+        assert_model("Currency", Currency::default());
+        assert_model("Symbol", symbol);
+        assert_model("Time", Time::default());
+        assert_model("Endonym", Endonym::default());
+        assert_model("Language", Language::default());
+        assert_model("Script", Script::default());
+    }
+
+    #[test]
+    fn enums_list_every_variant() {
+        let variants = |name: &str, values: Vec<Value>| {
+            let serialized = values.iter().filter_map(|v|v.as_str()).collect::<Vec<_>>();
+
+            assert_eq!(ENUMS.iter().find(|(e, _)|e.eq(&name)).unwrap().1, serialized);
+        };
+
+        // Matches fail to compile when variants are added
+        let kinds = [SymbolKind::Primary, SymbolKind::Narrow, SymbolKind::Plural];
+        match kinds[0] {
+            SymbolKind::Primary | SymbolKind::Narrow | SymbolKind::Plural => (),
+        }
+
+        let statuses = [LanguageStatus::Official, LanguageStatus::CoOfficial,
+            LanguageStatus::Recognized, LanguageStatus::WidelySpoken];
+        match statuses[0] {
+            LanguageStatus::Official | LanguageStatus::CoOfficial | LanguageStatus::Recognized
+            | LanguageStatus::WidelySpoken => (),
+        }
+
+        variants("SymbolKind", kinds.iter().map(|k|serde_json::to_value(k).unwrap()).collect());
+        variants("LanguageStatus", statuses.iter()
+            .map(|s|serde_json::to_value(s).unwrap()).collect());
+    }
+
+    #[test]
+    fn currency_codes_come_from_the_list() {
+        let s = codes_source(&BTreeMap::<String, Country>::new());
+
+        for code in crate::currency::iso_4217().keys() {
+            assert!(s.contains(&format!("    {},\n", code)), "{} missing", code);
+            assert!(code.parse::<CurrencyCode>().is_ok(), "{} not in src/codes.rs", code);
+        }
+
+        assert!(["RUB", "VES", "BOB"].iter().all(|c|c.parse::<CurrencyCode>().is_ok()));
+    }
+
+    #[test]
+    fn built_countries_are_of_the_types() {
+        let countries = serde_json::from_str::<BTreeMap<String, Country>>(
+            include_str!("../out.json")).unwrap();
+
+        for (k, v) in country_values(&countries) {
+            assert_eq!(mismatch(&Type::Struct("Country"), &v), None, "{}", k);
+        }
+    }
+}
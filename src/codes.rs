@@ -0,0 +1,3338 @@
+// Generated with `bubbles codegen` from the built countries, do not edit.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// ISO 3166-1 alpha-2 code of a country
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CountryCode {
+    AD,
+    AE,
+    AF,
+    AG,
+    AI,
+    AL,
+    AM,
+    AO,
+    AQ,
+    AR,
+    AS,
+    AT,
+    AU,
+    AW,
+    AX,
+    AZ,
+    BA,
+    BB,
+    BD,
+    BE,
+    BF,
+    BG,
+    BH,
+    BI,
+    BJ,
+    BL,
+    BM,
+    BN,
+    BO,
+    BQ,
+    BR,
+    BS,
+    BT,
+    BV,
+    BW,
+    BY,
+    BZ,
+    CA,
+    CC,
+    CD,
+    CF,
+    CG,
+    CH,
+    CI,
+    CK,
+    CL,
+    CM,
+    CN,
+    CO,
+    CR,
+    CU,
+    CV,
+    CW,
+    CX,
+    CY,
+    CZ,
+    DE,
+    DJ,
+    DK,
+    DM,
+    DO,
+    DZ,
+    EC,
+    EE,
+    EG,
+    EH,
+    ER,
+    ES,
+    ET,
+    FI,
+    FJ,
+    FK,
+    FM,
+    FO,
+    FR,
+    GA,
+    GB,
+    GD,
+    GE,
+    GF,
+    GG,
+    GH,
+    GI,
+    GL,
+    GM,
+    GN,
+    GP,
+    GQ,
+    GR,
+    GS,
+    GT,
+    GU,
+    GW,
+    GY,
+    HK,
+    HM,
+    HN,
+    HR,
+    HT,
+    HU,
+    ID,
+    IE,
+    IL,
+    IM,
+    IN,
+    IO,
+    IQ,
+    IR,
+    IS,
+    IT,
+    JE,
+    JM,
+    JO,
+    JP,
+    KE,
+    KG,
+    KH,
+    KI,
+    KM,
+    KN,
+    KP,
+    KR,
+    KW,
+    KY,
+    KZ,
+    LA,
+    LB,
+    LC,
+    LI,
+    LK,
+    LR,
+    LS,
+    LT,
+    LU,
+    LV,
+    LY,
+    MA,
+    MC,
+    MD,
+    ME,
+    MF,
+    MG,
+    MH,
+    MK,
+    ML,
+    MM,
+    MN,
+    MO,
+    MP,
+    MQ,
+    MR,
+    MS,
+    MT,
+    MU,
+    MV,
+    MW,
+    MX,
+    MY,
+    MZ,
+    NA,
+    NC,
+    NE,
+    NF,
+    NG,
+    NI,
+    NL,
+    NO,
+    NP,
+    NR,
+    NU,
+    NZ,
+    OM,
+    PA,
+    PE,
+    PF,
+    PG,
+    PH,
+    PK,
+    PL,
+    PM,
+    PN,
+    PR,
+    PS,
+    PT,
+    PW,
+    PY,
+    QA,
+    RE,
+    RO,
+    RS,
+    RU,
+    RW,
+    SA,
+    SB,
+    SC,
+    SD,
+    SE,
+    SG,
+    SH,
+    SI,
+    SJ,
+    SK,
+    SL,
+    SM,
+    SN,
+    SO,
+    SR,
+    SS,
+    ST,
+    SV,
+    SX,
+    SY,
+    SZ,
+    TC,
+    TD,
+    TF,
+    TG,
+    TH,
+    TJ,
+    TK,
+    TL,
+    TM,
+    TN,
+    TO,
+    TR,
+    TT,
+    TV,
+    TW,
+    TZ,
+    UA,
+    UG,
+    UM,
+    US,
+    UY,
+    UZ,
+    VA,
+    VC,
+    VE,
+    VG,
+    VI,
+    VN,
+    VU,
+    WF,
+    WS,
+    YE,
+    YT,
+    ZA,
+    ZM,
+    ZW,
+}
+
+impl CountryCode {
+    pub const ALL: [Self; 249] = [
+        Self::AD,
+        Self::AE,
+        Self::AF,
+        Self::AG,
+        Self::AI,
+        Self::AL,
+        Self::AM,
+        Self::AO,
+        Self::AQ,
+        Self::AR,
+        Self::AS,
+        Self::AT,
+        Self::AU,
+        Self::AW,
+        Self::AX,
+        Self::AZ,
+        Self::BA,
+        Self::BB,
+        Self::BD,
+        Self::BE,
+        Self::BF,
+        Self::BG,
+        Self::BH,
+        Self::BI,
+        Self::BJ,
+        Self::BL,
+        Self::BM,
+        Self::BN,
+        Self::BO,
+        Self::BQ,
+        Self::BR,
+        Self::BS,
+        Self::BT,
+        Self::BV,
+        Self::BW,
+        Self::BY,
+        Self::BZ,
+        Self::CA,
+        Self::CC,
+        Self::CD,
+        Self::CF,
+        Self::CG,
+        Self::CH,
+        Self::CI,
+        Self::CK,
+        Self::CL,
+        Self::CM,
+        Self::CN,
+        Self::CO,
+        Self::CR,
+        Self::CU,
+        Self::CV,
+        Self::CW,
+        Self::CX,
+        Self::CY,
+        Self::CZ,
+        Self::DE,
+        Self::DJ,
+        Self::DK,
+        Self::DM,
+        Self::DO,
+        Self::DZ,
+        Self::EC,
+        Self::EE,
+        Self::EG,
+        Self::EH,
+        Self::ER,
+        Self::ES,
+        Self::ET,
+        Self::FI,
+        Self::FJ,
+        Self::FK,
+        Self::FM,
+        Self::FO,
+        Self::FR,
+        Self::GA,
+        Self::GB,
+        Self::GD,
+        Self::GE,
+        Self::GF,
+        Self::GG,
+        Self::GH,
+        Self::GI,
+        Self::GL,
+        Self::GM,
+        Self::GN,
+        Self::GP,
+        Self::GQ,
+        Self::GR,
+        Self::GS,
+        Self::GT,
+        Self::GU,
+        Self::GW,
+        Self::GY,
+        Self::HK,
+        Self::HM,
+        Self::HN,
+        Self::HR,
+        Self::HT,
+        Self::HU,
+        Self::ID,
+        Self::IE,
+        Self::IL,
+        Self::IM,
+        Self::IN,
+        Self::IO,
+        Self::IQ,
+        Self::IR,
+        Self::IS,
+        Self::IT,
+        Self::JE,
+        Self::JM,
+        Self::JO,
+        Self::JP,
+        Self::KE,
+        Self::KG,
+        Self::KH,
+        Self::KI,
+        Self::KM,
+        Self::KN,
+        Self::KP,
+        Self::KR,
+        Self::KW,
+        Self::KY,
+        Self::KZ,
+        Self::LA,
+        Self::LB,
+        Self::LC,
+        Self::LI,
+        Self::LK,
+        Self::LR,
+        Self::LS,
+        Self::LT,
+        Self::LU,
+        Self::LV,
+        Self::LY,
+        Self::MA,
+        Self::MC,
+        Self::MD,
+        Self::ME,
+        Self::MF,
+        Self::MG,
+        Self::MH,
+        Self::MK,
+        Self::ML,
+        Self::MM,
+        Self::MN,
+        Self::MO,
+        Self::MP,
+        Self::MQ,
+        Self::MR,
+        Self::MS,
+        Self::MT,
+        Self::MU,
+        Self::MV,
+        Self::MW,
+        Self::MX,
+        Self::MY,
+        Self::MZ,
+        Self::NA,
+        Self::NC,
+        Self::NE,
+        Self::NF,
+        Self::NG,
+        Self::NI,
+        Self::NL,
+        Self::NO,
+        Self::NP,
+        Self::NR,
+        Self::NU,
+        Self::NZ,
+        Self::OM,
+        Self::PA,
+        Self::PE,
+        Self::PF,
+        Self::PG,
+        Self::PH,
+        Self::PK,
+        Self::PL,
+        Self::PM,
+        Self::PN,
+        Self::PR,
+        Self::PS,
+        Self::PT,
+        Self::PW,
+        Self::PY,
+        Self::QA,
+        Self::RE,
+        Self::RO,
+        Self::RS,
+        Self::RU,
+        Self::RW,
+        Self::SA,
+        Self::SB,
+        Self::SC,
+        Self::SD,
+        Self::SE,
+        Self::SG,
+        Self::SH,
+        Self::SI,
+        Self::SJ,
+        Self::SK,
+        Self::SL,
+        Self::SM,
+        Self::SN,
+        Self::SO,
+        Self::SR,
+        Self::SS,
+        Self::ST,
+        Self::SV,
+        Self::SX,
+        Self::SY,
+        Self::SZ,
+        Self::TC,
+        Self::TD,
+        Self::TF,
+        Self::TG,
+        Self::TH,
+        Self::TJ,
+        Self::TK,
+        Self::TL,
+        Self::TM,
+        Self::TN,
+        Self::TO,
+        Self::TR,
+        Self::TT,
+        Self::TV,
+        Self::TW,
+        Self::TZ,
+        Self::UA,
+        Self::UG,
+        Self::UM,
+        Self::US,
+        Self::UY,
+        Self::UZ,
+        Self::VA,
+        Self::VC,
+        Self::VE,
+        Self::VG,
+        Self::VI,
+        Self::VN,
+        Self::VU,
+        Self::WF,
+        Self::WS,
+        Self::YE,
+        Self::YT,
+        Self::ZA,
+        Self::ZM,
+        Self::ZW,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AD => "AD",
+            Self::AE => "AE",
+            Self::AF => "AF",
+            Self::AG => "AG",
+            Self::AI => "AI",
+            Self::AL => "AL",
+            Self::AM => "AM",
+            Self::AO => "AO",
+            Self::AQ => "AQ",
+            Self::AR => "AR",
+            Self::AS => "AS",
+            Self::AT => "AT",
+            Self::AU => "AU",
+            Self::AW => "AW",
+            Self::AX => "AX",
+            Self::AZ => "AZ",
+            Self::BA => "BA",
+            Self::BB => "BB",
+            Self::BD => "BD",
+            Self::BE => "BE",
+            Self::BF => "BF",
+            Self::BG => "BG",
+            Self::BH => "BH",
+            Self::BI => "BI",
+            Self::BJ => "BJ",
+            Self::BL => "BL",
+            Self::BM => "BM",
+            Self::BN => "BN",
+            Self::BO => "BO",
+            Self::BQ => "BQ",
+            Self::BR => "BR",
+            Self::BS => "BS",
+            Self::BT => "BT",
+            Self::BV => "BV",
+            Self::BW => "BW",
+            Self::BY => "BY",
+            Self::BZ => "BZ",
+            Self::CA => "CA",
+            Self::CC => "CC",
+            Self::CD => "CD",
+            Self::CF => "CF",
+            Self::CG => "CG",
+            Self::CH => "CH",
+            Self::CI => "CI",
+            Self::CK => "CK",
+            Self::CL => "CL",
+            Self::CM => "CM",
+            Self::CN => "CN",
+            Self::CO => "CO",
+            Self::CR => "CR",
+            Self::CU => "CU",
+            Self::CV => "CV",
+            Self::CW => "CW",
+            Self::CX => "CX",
+            Self::CY => "CY",
+            Self::CZ => "CZ",
+            Self::DE => "DE",
+            Self::DJ => "DJ",
+            Self::DK => "DK",
+            Self::DM => "DM",
+            Self::DO => "DO",
+            Self::DZ => "DZ",
+            Self::EC => "EC",
+            Self::EE => "EE",
+            Self::EG => "EG",
+            Self::EH => "EH",
+            Self::ER => "ER",
+            Self::ES => "ES",
+            Self::ET => "ET",
+            Self::FI => "FI",
+            Self::FJ => "FJ",
+            Self::FK => "FK",
+            Self::FM => "FM",
+            Self::FO => "FO",
+            Self::FR => "FR",
+            Self::GA => "GA",
+            Self::GB => "GB",
+            Self::GD => "GD",
+            Self::GE => "GE",
+            Self::GF => "GF",
+            Self::GG => "GG",
+            Self::GH => "GH",
+            Self::GI => "GI",
+            Self::GL => "GL",
+            Self::GM => "GM",
+            Self::GN => "GN",
+            Self::GP => "GP",
+            Self::GQ => "GQ",
+            Self::GR => "GR",
+            Self::GS => "GS",
+            Self::GT => "GT",
+            Self::GU => "GU",
+            Self::GW => "GW",
+            Self::GY => "GY",
+            Self::HK => "HK",
+            Self::HM => "HM",
+            Self::HN => "HN",
+            Self::HR => "HR",
+            Self::HT => "HT",
+            Self::HU => "HU",
+            Self::ID => "ID",
+            Self::IE => "IE",
+            Self::IL => "IL",
+            Self::IM => "IM",
+            Self::IN => "IN",
+            Self::IO => "IO",
+            Self::IQ => "IQ",
+            Self::IR => "IR",
+            Self::IS => "IS",
+            Self::IT => "IT",
+            Self::JE => "JE",
+            Self::JM => "JM",
+            Self::JO => "JO",
+            Self::JP => "JP",
+            Self::KE => "KE",
+            Self::KG => "KG",
+            Self::KH => "KH",
+            Self::KI => "KI",
+            Self::KM => "KM",
+            Self::KN => "KN",
+            Self::KP => "KP",
+            Self::KR => "KR",
+            Self::KW => "KW",
+            Self::KY => "KY",
+            Self::KZ => "KZ",
+            Self::LA => "LA",
+            Self::LB => "LB",
+            Self::LC => "LC",
+            Self::LI => "LI",
+            Self::LK => "LK",
+            Self::LR => "LR",
+            Self::LS => "LS",
+            Self::LT => "LT",
+            Self::LU => "LU",
+            Self::LV => "LV",
+            Self::LY => "LY",
+            Self::MA => "MA",
+            Self::MC => "MC",
+            Self::MD => "MD",
+            Self::ME => "ME",
+            Self::MF => "MF",
+            Self::MG => "MG",
+            Self::MH => "MH",
+            Self::MK => "MK",
+            Self::ML => "ML",
+            Self::MM => "MM",
+            Self::MN => "MN",
+            Self::MO => "MO",
+            Self::MP => "MP",
+            Self::MQ => "MQ",
+            Self::MR => "MR",
+            Self::MS => "MS",
+            Self::MT => "MT",
+            Self::MU => "MU",
+            Self::MV => "MV",
+            Self::MW => "MW",
+            Self::MX => "MX",
+            Self::MY => "MY",
+            Self::MZ => "MZ",
+            Self::NA => "NA",
+            Self::NC => "NC",
+            Self::NE => "NE",
+            Self::NF => "NF",
+            Self::NG => "NG",
+            Self::NI => "NI",
+            Self::NL => "NL",
+            Self::NO => "NO",
+            Self::NP => "NP",
+            Self::NR => "NR",
+            Self::NU => "NU",
+            Self::NZ => "NZ",
+            Self::OM => "OM",
+            Self::PA => "PA",
+            Self::PE => "PE",
+            Self::PF => "PF",
+            Self::PG => "PG",
+            Self::PH => "PH",
+            Self::PK => "PK",
+            Self::PL => "PL",
+            Self::PM => "PM",
+            Self::PN => "PN",
+            Self::PR => "PR",
+            Self::PS => "PS",
+            Self::PT => "PT",
+            Self::PW => "PW",
+            Self::PY => "PY",
+            Self::QA => "QA",
+            Self::RE => "RE",
+            Self::RO => "RO",
+            Self::RS => "RS",
+            Self::RU => "RU",
+            Self::RW => "RW",
+            Self::SA => "SA",
+            Self::SB => "SB",
+            Self::SC => "SC",
+            Self::SD => "SD",
+            Self::SE => "SE",
+            Self::SG => "SG",
+            Self::SH => "SH",
+            Self::SI => "SI",
+            Self::SJ => "SJ",
+            Self::SK => "SK",
+            Self::SL => "SL",
+            Self::SM => "SM",
+            Self::SN => "SN",
+            Self::SO => "SO",
+            Self::SR => "SR",
+            Self::SS => "SS",
+            Self::ST => "ST",
+            Self::SV => "SV",
+            Self::SX => "SX",
+            Self::SY => "SY",
+            Self::SZ => "SZ",
+            Self::TC => "TC",
+            Self::TD => "TD",
+            Self::TF => "TF",
+            Self::TG => "TG",
+            Self::TH => "TH",
+            Self::TJ => "TJ",
+            Self::TK => "TK",
+            Self::TL => "TL",
+            Self::TM => "TM",
+            Self::TN => "TN",
+            Self::TO => "TO",
+            Self::TR => "TR",
+            Self::TT => "TT",
+            Self::TV => "TV",
+            Self::TW => "TW",
+            Self::TZ => "TZ",
+            Self::UA => "UA",
+            Self::UG => "UG",
+            Self::UM => "UM",
+            Self::US => "US",
+            Self::UY => "UY",
+            Self::UZ => "UZ",
+            Self::VA => "VA",
+            Self::VC => "VC",
+            Self::VE => "VE",
+            Self::VG => "VG",
+            Self::VI => "VI",
+            Self::VN => "VN",
+            Self::VU => "VU",
+            Self::WF => "WF",
+            Self::WS => "WS",
+            Self::YE => "YE",
+            Self::YT => "YT",
+            Self::ZA => "ZA",
+            Self::ZM => "ZM",
+            Self::ZW => "ZW",
+        }
+    }
+}
+
+impl Display for CountryCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CountryCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "AD" => Ok(Self::AD),
+            "AE" => Ok(Self::AE),
+            "AF" => Ok(Self::AF),
+            "AG" => Ok(Self::AG),
+            "AI" => Ok(Self::AI),
+            "AL" => Ok(Self::AL),
+            "AM" => Ok(Self::AM),
+            "AO" => Ok(Self::AO),
+            "AQ" => Ok(Self::AQ),
+            "AR" => Ok(Self::AR),
+            "AS" => Ok(Self::AS),
+            "AT" => Ok(Self::AT),
+            "AU" => Ok(Self::AU),
+            "AW" => Ok(Self::AW),
+            "AX" => Ok(Self::AX),
+            "AZ" => Ok(Self::AZ),
+            "BA" => Ok(Self::BA),
+            "BB" => Ok(Self::BB),
+            "BD" => Ok(Self::BD),
+            "BE" => Ok(Self::BE),
+            "BF" => Ok(Self::BF),
+            "BG" => Ok(Self::BG),
+            "BH" => Ok(Self::BH),
+            "BI" => Ok(Self::BI),
+            "BJ" => Ok(Self::BJ),
+            "BL" => Ok(Self::BL),
+            "BM" => Ok(Self::BM),
+            "BN" => Ok(Self::BN),
+            "BO" => Ok(Self::BO),
+            "BQ" => Ok(Self::BQ),
+            "BR" => Ok(Self::BR),
+            "BS" => Ok(Self::BS),
+            "BT" => Ok(Self::BT),
+            "BV" => Ok(Self::BV),
+            "BW" => Ok(Self::BW),
+            "BY" => Ok(Self::BY),
+            "BZ" => Ok(Self::BZ),
+            "CA" => Ok(Self::CA),
+            "CC" => Ok(Self::CC),
+            "CD" => Ok(Self::CD),
+            "CF" => Ok(Self::CF),
+            "CG" => Ok(Self::CG),
+            "CH" => Ok(Self::CH),
+            "CI" => Ok(Self::CI),
+            "CK" => Ok(Self::CK),
+            "CL" => Ok(Self::CL),
+            "CM" => Ok(Self::CM),
+            "CN" => Ok(Self::CN),
+            "CO" => Ok(Self::CO),
+            "CR" => Ok(Self::CR),
+            "CU" => Ok(Self::CU),
+            "CV" => Ok(Self::CV),
+            "CW" => Ok(Self::CW),
+            "CX" => Ok(Self::CX),
+            "CY" => Ok(Self::CY),
+            "CZ" => Ok(Self::CZ),
+            "DE" => Ok(Self::DE),
+            "DJ" => Ok(Self::DJ),
+            "DK" => Ok(Self::DK),
+            "DM" => Ok(Self::DM),
+            "DO" => Ok(Self::DO),
+            "DZ" => Ok(Self::DZ),
+            "EC" => Ok(Self::EC),
+            "EE" => Ok(Self::EE),
+            "EG" => Ok(Self::EG),
+            "EH" => Ok(Self::EH),
+            "ER" => Ok(Self::ER),
+            "ES" => Ok(Self::ES),
+            "ET" => Ok(Self::ET),
+            "FI" => Ok(Self::FI),
+            "FJ" => Ok(Self::FJ),
+            "FK" => Ok(Self::FK),
+            "FM" => Ok(Self::FM),
+            "FO" => Ok(Self::FO),
+            "FR" => Ok(Self::FR),
+            "GA" => Ok(Self::GA),
+            "GB" => Ok(Self::GB),
+            "GD" => Ok(Self::GD),
+            "GE" => Ok(Self::GE),
+            "GF" => Ok(Self::GF),
+            "GG" => Ok(Self::GG),
+            "GH" => Ok(Self::GH),
+            "GI" => Ok(Self::GI),
+            "GL" => Ok(Self::GL),
+            "GM" => Ok(Self::GM),
+            "GN" => Ok(Self::GN),
+            "GP" => Ok(Self::GP),
+            "GQ" => Ok(Self::GQ),
+            "GR" => Ok(Self::GR),
+            "GS" => Ok(Self::GS),
+            "GT" => Ok(Self::GT),
+            "GU" => Ok(Self::GU),
+            "GW" => Ok(Self::GW),
+            "GY" => Ok(Self::GY),
+            "HK" => Ok(Self::HK),
+            "HM" => Ok(Self::HM),
+            "HN" => Ok(Self::HN),
+            "HR" => Ok(Self::HR),
+            "HT" => Ok(Self::HT),
+            "HU" => Ok(Self::HU),
+            "ID" => Ok(Self::ID),
+            "IE" => Ok(Self::IE),
+            "IL" => Ok(Self::IL),
+            "IM" => Ok(Self::IM),
+            "IN" => Ok(Self::IN),
+            "IO" => Ok(Self::IO),
+            "IQ" => Ok(Self::IQ),
+            "IR" => Ok(Self::IR),
+            "IS" => Ok(Self::IS),
+            "IT" => Ok(Self::IT),
+            "JE" => Ok(Self::JE),
+            "JM" => Ok(Self::JM),
+            "JO" => Ok(Self::JO),
+            "JP" => Ok(Self::JP),
+            "KE" => Ok(Self::KE),
+            "KG" => Ok(Self::KG),
+            "KH" => Ok(Self::KH),
+            "KI" => Ok(Self::KI),
+            "KM" => Ok(Self::KM),
+            "KN" => Ok(Self::KN),
+            "KP" => Ok(Self::KP),
+            "KR" => Ok(Self::KR),
+            "KW" => Ok(Self::KW),
+            "KY" => Ok(Self::KY),
+            "KZ" => Ok(Self::KZ),
+            "LA" => Ok(Self::LA),
+            "LB" => Ok(Self::LB),
+            "LC" => Ok(Self::LC),
+            "LI" => Ok(Self::LI),
+            "LK" => Ok(Self::LK),
+            "LR" => Ok(Self::LR),
+            "LS" => Ok(Self::LS),
+            "LT" => Ok(Self::LT),
+            "LU" => Ok(Self::LU),
+            "LV" => Ok(Self::LV),
+            "LY" => Ok(Self::LY),
+            "MA" => Ok(Self::MA),
+            "MC" => Ok(Self::MC),
+            "MD" => Ok(Self::MD),
+            "ME" => Ok(Self::ME),
+            "MF" => Ok(Self::MF),
+            "MG" => Ok(Self::MG),
+            "MH" => Ok(Self::MH),
+            "MK" => Ok(Self::MK),
+            "ML" => Ok(Self::ML),
+            "MM" => Ok(Self::MM),
+            "MN" => Ok(Self::MN),
+            "MO" => Ok(Self::MO),
+            "MP" => Ok(Self::MP),
+            "MQ" => Ok(Self::MQ),
+            "MR" => Ok(Self::MR),
+            "MS" => Ok(Self::MS),
+            "MT" => Ok(Self::MT),
+            "MU" => Ok(Self::MU),
+            "MV" => Ok(Self::MV),
+            "MW" => Ok(Self::MW),
+            "MX" => Ok(Self::MX),
+            "MY" => Ok(Self::MY),
+            "MZ" => Ok(Self::MZ),
+            "NA" => Ok(Self::NA),
+            "NC" => Ok(Self::NC),
+            "NE" => Ok(Self::NE),
+            "NF" => Ok(Self::NF),
+            "NG" => Ok(Self::NG),
+            "NI" => Ok(Self::NI),
+            "NL" => Ok(Self::NL),
+            "NO" => Ok(Self::NO),
+            "NP" => Ok(Self::NP),
+            "NR" => Ok(Self::NR),
+            "NU" => Ok(Self::NU),
+            "NZ" => Ok(Self::NZ),
+            "OM" => Ok(Self::OM),
+            "PA" => Ok(Self::PA),
+            "PE" => Ok(Self::PE),
+            "PF" => Ok(Self::PF),
+            "PG" => Ok(Self::PG),
+            "PH" => Ok(Self::PH),
+            "PK" => Ok(Self::PK),
+            "PL" => Ok(Self::PL),
+            "PM" => Ok(Self::PM),
+            "PN" => Ok(Self::PN),
+            "PR" => Ok(Self::PR),
+            "PS" => Ok(Self::PS),
+            "PT" => Ok(Self::PT),
+            "PW" => Ok(Self::PW),
+            "PY" => Ok(Self::PY),
+            "QA" => Ok(Self::QA),
+            "RE" => Ok(Self::RE),
+            "RO" => Ok(Self::RO),
+            "RS" => Ok(Self::RS),
+            "RU" => Ok(Self::RU),
+            "RW" => Ok(Self::RW),
+            "SA" => Ok(Self::SA),
+            "SB" => Ok(Self::SB),
+            "SC" => Ok(Self::SC),
+            "SD" => Ok(Self::SD),
+            "SE" => Ok(Self::SE),
+            "SG" => Ok(Self::SG),
+            "SH" => Ok(Self::SH),
+            "SI" => Ok(Self::SI),
+            "SJ" => Ok(Self::SJ),
+            "SK" => Ok(Self::SK),
+            "SL" => Ok(Self::SL),
+            "SM" => Ok(Self::SM),
+            "SN" => Ok(Self::SN),
+            "SO" => Ok(Self::SO),
+            "SR" => Ok(Self::SR),
+            "SS" => Ok(Self::SS),
+            "ST" => Ok(Self::ST),
+            "SV" => Ok(Self::SV),
+            "SX" => Ok(Self::SX),
+            "SY" => Ok(Self::SY),
+            "SZ" => Ok(Self::SZ),
+            "TC" => Ok(Self::TC),
+            "TD" => Ok(Self::TD),
+            "TF" => Ok(Self::TF),
+            "TG" => Ok(Self::TG),
+            "TH" => Ok(Self::TH),
+            "TJ" => Ok(Self::TJ),
+            "TK" => Ok(Self::TK),
+            "TL" => Ok(Self::TL),
+            "TM" => Ok(Self::TM),
+            "TN" => Ok(Self::TN),
+            "TO" => Ok(Self::TO),
+            "TR" => Ok(Self::TR),
+            "TT" => Ok(Self::TT),
+            "TV" => Ok(Self::TV),
+            "TW" => Ok(Self::TW),
+            "TZ" => Ok(Self::TZ),
+            "UA" => Ok(Self::UA),
+            "UG" => Ok(Self::UG),
+            "UM" => Ok(Self::UM),
+            "US" => Ok(Self::US),
+            "UY" => Ok(Self::UY),
+            "UZ" => Ok(Self::UZ),
+            "VA" => Ok(Self::VA),
+            "VC" => Ok(Self::VC),
+            "VE" => Ok(Self::VE),
+            "VG" => Ok(Self::VG),
+            "VI" => Ok(Self::VI),
+            "VN" => Ok(Self::VN),
+            "VU" => Ok(Self::VU),
+            "WF" => Ok(Self::WF),
+            "WS" => Ok(Self::WS),
+            "YE" => Ok(Self::YE),
+            "YT" => Ok(Self::YT),
+            "ZA" => Ok(Self::ZA),
+            "ZM" => Ok(Self::ZM),
+            "ZW" => Ok(Self::ZW),
+            _ => Err(Error::UnknownCode { kind: "country".into(), code: s.to_string() }),
+        }
+    }
+}
+
+impl CountryCode {
+    pub fn alpha3(&self) -> Option<CountryCodeA3> {
+        match self {
+            Self::AD => Some(CountryCodeA3::AND),
+            Self::AE => Some(CountryCodeA3::ARE),
+            Self::AF => Some(CountryCodeA3::AFG),
+            Self::AG => Some(CountryCodeA3::ATG),
+            Self::AI => Some(CountryCodeA3::AIA),
+            Self::AL => Some(CountryCodeA3::ALB),
+            Self::AM => Some(CountryCodeA3::ARM),
+            Self::AO => Some(CountryCodeA3::AGO),
+            Self::AQ => Some(CountryCodeA3::ATA),
+            Self::AR => Some(CountryCodeA3::ARG),
+            Self::AS => Some(CountryCodeA3::ASM),
+            Self::AT => Some(CountryCodeA3::AUT),
+            Self::AU => Some(CountryCodeA3::AUS),
+            Self::AW => Some(CountryCodeA3::ABW),
+            Self::AX => Some(CountryCodeA3::ALA),
+            Self::AZ => Some(CountryCodeA3::AZE),
+            Self::BA => Some(CountryCodeA3::BIH),
+            Self::BB => Some(CountryCodeA3::BRB),
+            Self::BD => Some(CountryCodeA3::BGD),
+            Self::BE => Some(CountryCodeA3::BEL),
+            Self::BF => Some(CountryCodeA3::BFA),
+            Self::BG => Some(CountryCodeA3::BGR),
+            Self::BH => Some(CountryCodeA3::BHR),
+            Self::BI => Some(CountryCodeA3::BDI),
+            Self::BJ => Some(CountryCodeA3::BEN),
+            Self::BL => Some(CountryCodeA3::BLM),
+            Self::BM => Some(CountryCodeA3::BMU),
+            Self::BN => Some(CountryCodeA3::BRN),
+            Self::BO => Some(CountryCodeA3::BOL),
+            Self::BQ => Some(CountryCodeA3::BES),
+            Self::BR => Some(CountryCodeA3::BRA),
+            Self::BS => Some(CountryCodeA3::BHS),
+            Self::BT => Some(CountryCodeA3::BTN),
+            Self::BV => Some(CountryCodeA3::BVT),
+            Self::BW => Some(CountryCodeA3::BWA),
+            Self::BY => Some(CountryCodeA3::BLR),
+            Self::BZ => Some(CountryCodeA3::BLZ),
+            Self::CA => Some(CountryCodeA3::CAN),
+            Self::CC => Some(CountryCodeA3::CCK),
+            Self::CD => Some(CountryCodeA3::COD),
+            Self::CF => Some(CountryCodeA3::CAF),
+            Self::CG => Some(CountryCodeA3::COG),
+            Self::CH => Some(CountryCodeA3::CHE),
+            Self::CI => Some(CountryCodeA3::CIV),
+            Self::CK => Some(CountryCodeA3::COK),
+            Self::CL => Some(CountryCodeA3::CHL),
+            Self::CM => Some(CountryCodeA3::CMR),
+            Self::CN => Some(CountryCodeA3::CHN),
+            Self::CO => Some(CountryCodeA3::COL),
+            Self::CR => Some(CountryCodeA3::CRI),
+            Self::CU => Some(CountryCodeA3::CUB),
+            Self::CV => Some(CountryCodeA3::CPV),
+            Self::CW => Some(CountryCodeA3::CUW),
+            Self::CX => Some(CountryCodeA3::CXR),
+            Self::CY => Some(CountryCodeA3::CYP),
+            Self::CZ => Some(CountryCodeA3::CZE),
+            Self::DE => Some(CountryCodeA3::DEU),
+            Self::DJ => Some(CountryCodeA3::DJI),
+            Self::DK => Some(CountryCodeA3::DNK),
+            Self::DM => Some(CountryCodeA3::DMA),
+            Self::DO => Some(CountryCodeA3::DOM),
+            Self::DZ => Some(CountryCodeA3::DZA),
+            Self::EC => Some(CountryCodeA3::ECU),
+            Self::EE => Some(CountryCodeA3::EST),
+            Self::EG => Some(CountryCodeA3::EGY),
+            Self::EH => Some(CountryCodeA3::ESH),
+            Self::ER => Some(CountryCodeA3::ERI),
+            Self::ES => Some(CountryCodeA3::ESP),
+            Self::ET => Some(CountryCodeA3::ETH),
+            Self::FI => Some(CountryCodeA3::FIN),
+            Self::FJ => Some(CountryCodeA3::FJI),
+            Self::FK => Some(CountryCodeA3::FLK),
+            Self::FM => Some(CountryCodeA3::FSM),
+            Self::FO => Some(CountryCodeA3::FRO),
+            Self::FR => Some(CountryCodeA3::FRA),
+            Self::GA => Some(CountryCodeA3::GAB),
+            Self::GB => Some(CountryCodeA3::GBR),
+            Self::GD => Some(CountryCodeA3::GRD),
+            Self::GE => Some(CountryCodeA3::GEO),
+            Self::GF => Some(CountryCodeA3::GUF),
+            Self::GG => Some(CountryCodeA3::GGY),
+            Self::GH => Some(CountryCodeA3::GHA),
+            Self::GI => Some(CountryCodeA3::GIB),
+            Self::GL => Some(CountryCodeA3::GRL),
+            Self::GM => Some(CountryCodeA3::GMB),
+            Self::GN => Some(CountryCodeA3::GIN),
+            Self::GP => Some(CountryCodeA3::GLP),
+            Self::GQ => Some(CountryCodeA3::GNQ),
+            Self::GR => Some(CountryCodeA3::GRC),
+            Self::GS => Some(CountryCodeA3::SGS),
+            Self::GT => Some(CountryCodeA3::GTM),
+            Self::GU => Some(CountryCodeA3::GUM),
+            Self::GW => Some(CountryCodeA3::GNB),
+            Self::GY => Some(CountryCodeA3::GUY),
+            Self::HK => Some(CountryCodeA3::HKG),
+            Self::HM => Some(CountryCodeA3::HMD),
+            Self::HN => Some(CountryCodeA3::HND),
+            Self::HR => Some(CountryCodeA3::HRV),
+            Self::HT => Some(CountryCodeA3::HTI),
+            Self::HU => Some(CountryCodeA3::HUN),
+            Self::ID => Some(CountryCodeA3::IDN),
+            Self::IE => Some(CountryCodeA3::IRL),
+            Self::IL => Some(CountryCodeA3::ISR),
+            Self::IM => Some(CountryCodeA3::IMN),
+            Self::IN => Some(CountryCodeA3::IND),
+            Self::IO => Some(CountryCodeA3::IOT),
+            Self::IQ => Some(CountryCodeA3::IRQ),
+            Self::IR => Some(CountryCodeA3::IRN),
+            Self::IS => Some(CountryCodeA3::ISL),
+            Self::IT => Some(CountryCodeA3::ITA),
+            Self::JE => Some(CountryCodeA3::JEY),
+            Self::JM => Some(CountryCodeA3::JAM),
+            Self::JO => Some(CountryCodeA3::JOR),
+            Self::JP => Some(CountryCodeA3::JPN),
+            Self::KE => Some(CountryCodeA3::KEN),
+            Self::KG => Some(CountryCodeA3::KGZ),
+            Self::KH => Some(CountryCodeA3::KHM),
+            Self::KI => Some(CountryCodeA3::KIR),
+            Self::KM => Some(CountryCodeA3::COM),
+            Self::KN => Some(CountryCodeA3::KNA),
+            Self::KP => Some(CountryCodeA3::PRK),
+            Self::KR => Some(CountryCodeA3::KOR),
+            Self::KW => Some(CountryCodeA3::KWT),
+            Self::KY => Some(CountryCodeA3::CYM),
+            Self::KZ => Some(CountryCodeA3::KAZ),
+            Self::LA => Some(CountryCodeA3::LAO),
+            Self::LB => Some(CountryCodeA3::LBN),
+            Self::LC => Some(CountryCodeA3::LCA),
+            Self::LI => Some(CountryCodeA3::LIE),
+            Self::LK => Some(CountryCodeA3::LKA),
+            Self::LR => Some(CountryCodeA3::LBR),
+            Self::LS => Some(CountryCodeA3::LSO),
+            Self::LT => Some(CountryCodeA3::LTU),
+            Self::LU => Some(CountryCodeA3::LUX),
+            Self::LV => Some(CountryCodeA3::LVA),
+            Self::LY => Some(CountryCodeA3::LBY),
+            Self::MA => Some(CountryCodeA3::MAR),
+            Self::MC => Some(CountryCodeA3::MCO),
+            Self::MD => Some(CountryCodeA3::MDA),
+            Self::ME => Some(CountryCodeA3::MNE),
+            Self::MF => Some(CountryCodeA3::MAF),
+            Self::MG => Some(CountryCodeA3::MDG),
+            Self::MH => Some(CountryCodeA3::MHL),
+            Self::MK => Some(CountryCodeA3::MKD),
+            Self::ML => Some(CountryCodeA3::MLI),
+            Self::MM => Some(CountryCodeA3::MMR),
+            Self::MN => Some(CountryCodeA3::MNG),
+            Self::MO => Some(CountryCodeA3::MAC),
+            Self::MP => Some(CountryCodeA3::MNP),
+            Self::MQ => Some(CountryCodeA3::MTQ),
+            Self::MR => Some(CountryCodeA3::MRT),
+            Self::MS => Some(CountryCodeA3::MSR),
+            Self::MT => Some(CountryCodeA3::MLT),
+            Self::MU => Some(CountryCodeA3::MUS),
+            Self::MV => Some(CountryCodeA3::MDV),
+            Self::MW => Some(CountryCodeA3::MWI),
+            Self::MX => Some(CountryCodeA3::MEX),
+            Self::MY => Some(CountryCodeA3::MYS),
+            Self::MZ => Some(CountryCodeA3::MOZ),
+            Self::NA => Some(CountryCodeA3::NAM),
+            Self::NC => Some(CountryCodeA3::NCL),
+            Self::NE => Some(CountryCodeA3::NER),
+            Self::NF => Some(CountryCodeA3::NFK),
+            Self::NG => Some(CountryCodeA3::NGA),
+            Self::NI => Some(CountryCodeA3::NIC),
+            Self::NL => Some(CountryCodeA3::NLD),
+            Self::NO => Some(CountryCodeA3::NOR),
+            Self::NP => Some(CountryCodeA3::NPL),
+            Self::NR => Some(CountryCodeA3::NRU),
+            Self::NU => Some(CountryCodeA3::NIU),
+            Self::NZ => Some(CountryCodeA3::NZL),
+            Self::OM => Some(CountryCodeA3::OMN),
+            Self::PA => Some(CountryCodeA3::PAN),
+            Self::PE => Some(CountryCodeA3::PER),
+            Self::PF => Some(CountryCodeA3::PYF),
+            Self::PG => Some(CountryCodeA3::PNG),
+            Self::PH => Some(CountryCodeA3::PHL),
+            Self::PK => Some(CountryCodeA3::PAK),
+            Self::PL => Some(CountryCodeA3::POL),
+            Self::PM => Some(CountryCodeA3::SPM),
+            Self::PN => Some(CountryCodeA3::PCN),
+            Self::PR => Some(CountryCodeA3::PRI),
+            Self::PS => Some(CountryCodeA3::PSE),
+            Self::PT => Some(CountryCodeA3::PRT),
+            Self::PW => Some(CountryCodeA3::PLW),
+            Self::PY => Some(CountryCodeA3::PRY),
+            Self::QA => Some(CountryCodeA3::QAT),
+            Self::RE => Some(CountryCodeA3::REU),
+            Self::RO => Some(CountryCodeA3::ROU),
+            Self::RS => Some(CountryCodeA3::SRB),
+            Self::RU => Some(CountryCodeA3::RUS),
+            Self::RW => Some(CountryCodeA3::RWA),
+            Self::SA => Some(CountryCodeA3::SAU),
+            Self::SB => Some(CountryCodeA3::SLB),
+            Self::SC => Some(CountryCodeA3::SYC),
+            Self::SD => Some(CountryCodeA3::SDN),
+            Self::SE => Some(CountryCodeA3::SWE),
+            Self::SG => Some(CountryCodeA3::SGP),
+            Self::SH => Some(CountryCodeA3::SHN),
+            Self::SI => Some(CountryCodeA3::SVN),
+            Self::SJ => Some(CountryCodeA3::SJM),
+            Self::SK => Some(CountryCodeA3::SVK),
+            Self::SL => Some(CountryCodeA3::SLE),
+            Self::SM => Some(CountryCodeA3::SMR),
+            Self::SN => Some(CountryCodeA3::SEN),
+            Self::SO => Some(CountryCodeA3::SOM),
+            Self::SR => Some(CountryCodeA3::SUR),
+            Self::SS => Some(CountryCodeA3::SSD),
+            Self::ST => Some(CountryCodeA3::STP),
+            Self::SV => Some(CountryCodeA3::SLV),
+            Self::SX => Some(CountryCodeA3::SXM),
+            Self::SY => Some(CountryCodeA3::SYR),
+            Self::SZ => Some(CountryCodeA3::SWZ),
+            Self::TC => Some(CountryCodeA3::TCA),
+            Self::TD => Some(CountryCodeA3::TCD),
+            Self::TF => Some(CountryCodeA3::ATF),
+            Self::TG => Some(CountryCodeA3::TGO),
+            Self::TH => Some(CountryCodeA3::THA),
+            Self::TJ => Some(CountryCodeA3::TJK),
+            Self::TK => Some(CountryCodeA3::TKL),
+            Self::TL => Some(CountryCodeA3::TLS),
+            Self::TM => Some(CountryCodeA3::TKM),
+            Self::TN => Some(CountryCodeA3::TUN),
+            Self::TO => Some(CountryCodeA3::TON),
+            Self::TR => Some(CountryCodeA3::TUR),
+            Self::TT => Some(CountryCodeA3::TTO),
+            Self::TV => Some(CountryCodeA3::TUV),
+            Self::TW => Some(CountryCodeA3::TWN),
+            Self::TZ => Some(CountryCodeA3::TZA),
+            Self::UA => Some(CountryCodeA3::UKR),
+            Self::UG => Some(CountryCodeA3::UGA),
+            Self::UM => Some(CountryCodeA3::UMI),
+            Self::US => Some(CountryCodeA3::USA),
+            Self::UY => Some(CountryCodeA3::URY),
+            Self::UZ => Some(CountryCodeA3::UZB),
+            Self::VA => Some(CountryCodeA3::VAT),
+            Self::VC => Some(CountryCodeA3::VCT),
+            Self::VE => Some(CountryCodeA3::VEN),
+            Self::VG => Some(CountryCodeA3::VGB),
+            Self::VI => Some(CountryCodeA3::VIR),
+            Self::VN => Some(CountryCodeA3::VNM),
+            Self::VU => Some(CountryCodeA3::VUT),
+            Self::WF => Some(CountryCodeA3::WLF),
+            Self::WS => Some(CountryCodeA3::WSM),
+            Self::YE => Some(CountryCodeA3::YEM),
+            Self::YT => Some(CountryCodeA3::MYT),
+            Self::ZA => Some(CountryCodeA3::ZAF),
+            Self::ZM => Some(CountryCodeA3::ZMB),
+            Self::ZW => Some(CountryCodeA3::ZWE),
+        }
+    }
+}
+
+/// ISO 3166-1 alpha-3 code of a country
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CountryCodeA3 {
+    ABW,
+    AFG,
+    AGO,
+    AIA,
+    ALA,
+    ALB,
+    AND,
+    ARE,
+    ARG,
+    ARM,
+    ASM,
+    ATA,
+    ATF,
+    ATG,
+    AUS,
+    AUT,
+    AZE,
+    BDI,
+    BEL,
+    BEN,
+    BES,
+    BFA,
+    BGD,
+    BGR,
+    BHR,
+    BHS,
+    BIH,
+    BLM,
+    BLR,
+    BLZ,
+    BMU,
+    BOL,
+    BRA,
+    BRB,
+    BRN,
+    BTN,
+    BVT,
+    BWA,
+    CAF,
+    CAN,
+    CCK,
+    CHE,
+    CHL,
+    CHN,
+    CIV,
+    CMR,
+    COD,
+    COG,
+    COK,
+    COL,
+    COM,
+    CPV,
+    CRI,
+    CUB,
+    CUW,
+    CXR,
+    CYM,
+    CYP,
+    CZE,
+    DEU,
+    DJI,
+    DMA,
+    DNK,
+    DOM,
+    DZA,
+    ECU,
+    EGY,
+    ERI,
+    ESH,
+    ESP,
+    EST,
+    ETH,
+    FIN,
+    FJI,
+    FLK,
+    FRA,
+    FRO,
+    FSM,
+    GAB,
+    GBR,
+    GEO,
+    GGY,
+    GHA,
+    GIB,
+    GIN,
+    GLP,
+    GMB,
+    GNB,
+    GNQ,
+    GRC,
+    GRD,
+    GRL,
+    GTM,
+    GUF,
+    GUM,
+    GUY,
+    HKG,
+    HMD,
+    HND,
+    HRV,
+    HTI,
+    HUN,
+    IDN,
+    IMN,
+    IND,
+    IOT,
+    IRL,
+    IRN,
+    IRQ,
+    ISL,
+    ISR,
+    ITA,
+    JAM,
+    JEY,
+    JOR,
+    JPN,
+    KAZ,
+    KEN,
+    KGZ,
+    KHM,
+    KIR,
+    KNA,
+    KOR,
+    KWT,
+    LAO,
+    LBN,
+    LBR,
+    LBY,
+    LCA,
+    LIE,
+    LKA,
+    LSO,
+    LTU,
+    LUX,
+    LVA,
+    MAC,
+    MAF,
+    MAR,
+    MCO,
+    MDA,
+    MDG,
+    MDV,
+    MEX,
+    MHL,
+    MKD,
+    MLI,
+    MLT,
+    MMR,
+    MNE,
+    MNG,
+    MNP,
+    MOZ,
+    MRT,
+    MSR,
+    MTQ,
+    MUS,
+    MWI,
+    MYS,
+    MYT,
+    NAM,
+    NCL,
+    NER,
+    NFK,
+    NGA,
+    NIC,
+    NIU,
+    NLD,
+    NOR,
+    NPL,
+    NRU,
+    NZL,
+    OMN,
+    PAK,
+    PAN,
+    PCN,
+    PER,
+    PHL,
+    PLW,
+    PNG,
+    POL,
+    PRI,
+    PRK,
+    PRT,
+    PRY,
+    PSE,
+    PYF,
+    QAT,
+    REU,
+    ROU,
+    RUS,
+    RWA,
+    SAU,
+    SDN,
+    SEN,
+    SGP,
+    SGS,
+    SHN,
+    SJM,
+    SLB,
+    SLE,
+    SLV,
+    SMR,
+    SOM,
+    SPM,
+    SRB,
+    SSD,
+    STP,
+    SUR,
+    SVK,
+    SVN,
+    SWE,
+    SWZ,
+    SXM,
+    SYC,
+    SYR,
+    TCA,
+    TCD,
+    TGO,
+    THA,
+    TJK,
+    TKL,
+    TKM,
+    TLS,
+    TON,
+    TTO,
+    TUN,
+    TUR,
+    TUV,
+    TWN,
+    TZA,
+    UGA,
+    UKR,
+    UMI,
+    URY,
+    USA,
+    UZB,
+    VAT,
+    VCT,
+    VEN,
+    VGB,
+    VIR,
+    VNM,
+    VUT,
+    WLF,
+    WSM,
+    YEM,
+    ZAF,
+    ZMB,
+    ZWE,
+}
+
+impl CountryCodeA3 {
+    pub const ALL: [Self; 249] = [
+        Self::ABW,
+        Self::AFG,
+        Self::AGO,
+        Self::AIA,
+        Self::ALA,
+        Self::ALB,
+        Self::AND,
+        Self::ARE,
+        Self::ARG,
+        Self::ARM,
+        Self::ASM,
+        Self::ATA,
+        Self::ATF,
+        Self::ATG,
+        Self::AUS,
+        Self::AUT,
+        Self::AZE,
+        Self::BDI,
+        Self::BEL,
+        Self::BEN,
+        Self::BES,
+        Self::BFA,
+        Self::BGD,
+        Self::BGR,
+        Self::BHR,
+        Self::BHS,
+        Self::BIH,
+        Self::BLM,
+        Self::BLR,
+        Self::BLZ,
+        Self::BMU,
+        Self::BOL,
+        Self::BRA,
+        Self::BRB,
+        Self::BRN,
+        Self::BTN,
+        Self::BVT,
+        Self::BWA,
+        Self::CAF,
+        Self::CAN,
+        Self::CCK,
+        Self::CHE,
+        Self::CHL,
+        Self::CHN,
+        Self::CIV,
+        Self::CMR,
+        Self::COD,
+        Self::COG,
+        Self::COK,
+        Self::COL,
+        Self::COM,
+        Self::CPV,
+        Self::CRI,
+        Self::CUB,
+        Self::CUW,
+        Self::CXR,
+        Self::CYM,
+        Self::CYP,
+        Self::CZE,
+        Self::DEU,
+        Self::DJI,
+        Self::DMA,
+        Self::DNK,
+        Self::DOM,
+        Self::DZA,
+        Self::ECU,
+        Self::EGY,
+        Self::ERI,
+        Self::ESH,
+        Self::ESP,
+        Self::EST,
+        Self::ETH,
+        Self::FIN,
+        Self::FJI,
+        Self::FLK,
+        Self::FRA,
+        Self::FRO,
+        Self::FSM,
+        Self::GAB,
+        Self::GBR,
+        Self::GEO,
+        Self::GGY,
+        Self::GHA,
+        Self::GIB,
+        Self::GIN,
+        Self::GLP,
+        Self::GMB,
+        Self::GNB,
+        Self::GNQ,
+        Self::GRC,
+        Self::GRD,
+        Self::GRL,
+        Self::GTM,
+        Self::GUF,
+        Self::GUM,
+        Self::GUY,
+        Self::HKG,
+        Self::HMD,
+        Self::HND,
+        Self::HRV,
+        Self::HTI,
+        Self::HUN,
+        Self::IDN,
+        Self::IMN,
+        Self::IND,
+        Self::IOT,
+        Self::IRL,
+        Self::IRN,
+        Self::IRQ,
+        Self::ISL,
+        Self::ISR,
+        Self::ITA,
+        Self::JAM,
+        Self::JEY,
+        Self::JOR,
+        Self::JPN,
+        Self::KAZ,
+        Self::KEN,
+        Self::KGZ,
+        Self::KHM,
+        Self::KIR,
+        Self::KNA,
+        Self::KOR,
+        Self::KWT,
+        Self::LAO,
+        Self::LBN,
+        Self::LBR,
+        Self::LBY,
+        Self::LCA,
+        Self::LIE,
+        Self::LKA,
+        Self::LSO,
+        Self::LTU,
+        Self::LUX,
+        Self::LVA,
+        Self::MAC,
+        Self::MAF,
+        Self::MAR,
+        Self::MCO,
+        Self::MDA,
+        Self::MDG,
+        Self::MDV,
+        Self::MEX,
+        Self::MHL,
+        Self::MKD,
+        Self::MLI,
+        Self::MLT,
+        Self::MMR,
+        Self::MNE,
+        Self::MNG,
+        Self::MNP,
+        Self::MOZ,
+        Self::MRT,
+        Self::MSR,
+        Self::MTQ,
+        Self::MUS,
+        Self::MWI,
+        Self::MYS,
+        Self::MYT,
+        Self::NAM,
+        Self::NCL,
+        Self::NER,
+        Self::NFK,
+        Self::NGA,
+        Self::NIC,
+        Self::NIU,
+        Self::NLD,
+        Self::NOR,
+        Self::NPL,
+        Self::NRU,
+        Self::NZL,
+        Self::OMN,
+        Self::PAK,
+        Self::PAN,
+        Self::PCN,
+        Self::PER,
+        Self::PHL,
+        Self::PLW,
+        Self::PNG,
+        Self::POL,
+        Self::PRI,
+        Self::PRK,
+        Self::PRT,
+        Self::PRY,
+        Self::PSE,
+        Self::PYF,
+        Self::QAT,
+        Self::REU,
+        Self::ROU,
+        Self::RUS,
+        Self::RWA,
+        Self::SAU,
+        Self::SDN,
+        Self::SEN,
+        Self::SGP,
+        Self::SGS,
+        Self::SHN,
+        Self::SJM,
+        Self::SLB,
+        Self::SLE,
+        Self::SLV,
+        Self::SMR,
+        Self::SOM,
+        Self::SPM,
+        Self::SRB,
+        Self::SSD,
+        Self::STP,
+        Self::SUR,
+        Self::SVK,
+        Self::SVN,
+        Self::SWE,
+        Self::SWZ,
+        Self::SXM,
+        Self::SYC,
+        Self::SYR,
+        Self::TCA,
+        Self::TCD,
+        Self::TGO,
+        Self::THA,
+        Self::TJK,
+        Self::TKL,
+        Self::TKM,
+        Self::TLS,
+        Self::TON,
+        Self::TTO,
+        Self::TUN,
+        Self::TUR,
+        Self::TUV,
+        Self::TWN,
+        Self::TZA,
+        Self::UGA,
+        Self::UKR,
+        Self::UMI,
+        Self::URY,
+        Self::USA,
+        Self::UZB,
+        Self::VAT,
+        Self::VCT,
+        Self::VEN,
+        Self::VGB,
+        Self::VIR,
+        Self::VNM,
+        Self::VUT,
+        Self::WLF,
+        Self::WSM,
+        Self::YEM,
+        Self::ZAF,
+        Self::ZMB,
+        Self::ZWE,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ABW => "ABW",
+            Self::AFG => "AFG",
+            Self::AGO => "AGO",
+            Self::AIA => "AIA",
+            Self::ALA => "ALA",
+            Self::ALB => "ALB",
+            Self::AND => "AND",
+            Self::ARE => "ARE",
+            Self::ARG => "ARG",
+            Self::ARM => "ARM",
+            Self::ASM => "ASM",
+            Self::ATA => "ATA",
+            Self::ATF => "ATF",
+            Self::ATG => "ATG",
+            Self::AUS => "AUS",
+            Self::AUT => "AUT",
+            Self::AZE => "AZE",
+            Self::BDI => "BDI",
+            Self::BEL => "BEL",
+            Self::BEN => "BEN",
+            Self::BES => "BES",
+            Self::BFA => "BFA",
+            Self::BGD => "BGD",
+            Self::BGR => "BGR",
+            Self::BHR => "BHR",
+            Self::BHS => "BHS",
+            Self::BIH => "BIH",
+            Self::BLM => "BLM",
+            Self::BLR => "BLR",
+            Self::BLZ => "BLZ",
+            Self::BMU => "BMU",
+            Self::BOL => "BOL",
+            Self::BRA => "BRA",
+            Self::BRB => "BRB",
+            Self::BRN => "BRN",
+            Self::BTN => "BTN",
+            Self::BVT => "BVT",
+            Self::BWA => "BWA",
+            Self::CAF => "CAF",
+            Self::CAN => "CAN",
+            Self::CCK => "CCK",
+            Self::CHE => "CHE",
+            Self::CHL => "CHL",
+            Self::CHN => "CHN",
+            Self::CIV => "CIV",
+            Self::CMR => "CMR",
+            Self::COD => "COD",
+            Self::COG => "COG",
+            Self::COK => "COK",
+            Self::COL => "COL",
+            Self::COM => "COM",
+            Self::CPV => "CPV",
+            Self::CRI => "CRI",
+            Self::CUB => "CUB",
+            Self::CUW => "CUW",
+            Self::CXR => "CXR",
+            Self::CYM => "CYM",
+            Self::CYP => "CYP",
+            Self::CZE => "CZE",
+            Self::DEU => "DEU",
+            Self::DJI => "DJI",
+            Self::DMA => "DMA",
+            Self::DNK => "DNK",
+            Self::DOM => "DOM",
+            Self::DZA => "DZA",
+            Self::ECU => "ECU",
+            Self::EGY => "EGY",
+            Self::ERI => "ERI",
+            Self::ESH => "ESH",
+            Self::ESP => "ESP",
+            Self::EST => "EST",
+            Self::ETH => "ETH",
+            Self::FIN => "FIN",
+            Self::FJI => "FJI",
+            Self::FLK => "FLK",
+            Self::FRA => "FRA",
+            Self::FRO => "FRO",
+            Self::FSM => "FSM",
+            Self::GAB => "GAB",
+            Self::GBR => "GBR",
+            Self::GEO => "GEO",
+            Self::GGY => "GGY",
+            Self::GHA => "GHA",
+            Self::GIB => "GIB",
+            Self::GIN => "GIN",
+            Self::GLP => "GLP",
+            Self::GMB => "GMB",
+            Self::GNB => "GNB",
+            Self::GNQ => "GNQ",
+            Self::GRC => "GRC",
+            Self::GRD => "GRD",
+            Self::GRL => "GRL",
+            Self::GTM => "GTM",
+            Self::GUF => "GUF",
+            Self::GUM => "GUM",
+            Self::GUY => "GUY",
+            Self::HKG => "HKG",
+            Self::HMD => "HMD",
+            Self::HND => "HND",
+            Self::HRV => "HRV",
+            Self::HTI => "HTI",
+            Self::HUN => "HUN",
+            Self::IDN => "IDN",
+            Self::IMN => "IMN",
+            Self::IND => "IND",
+            Self::IOT => "IOT",
+            Self::IRL => "IRL",
+            Self::IRN => "IRN",
+            Self::IRQ => "IRQ",
+            Self::ISL => "ISL",
+            Self::ISR => "ISR",
+            Self::ITA => "ITA",
+            Self::JAM => "JAM",
+            Self::JEY => "JEY",
+            Self::JOR => "JOR",
+            Self::JPN => "JPN",
+            Self::KAZ => "KAZ",
+            Self::KEN => "KEN",
+            Self::KGZ => "KGZ",
+            Self::KHM => "KHM",
+            Self::KIR => "KIR",
+            Self::KNA => "KNA",
+            Self::KOR => "KOR",
+            Self::KWT => "KWT",
+            Self::LAO => "LAO",
+            Self::LBN => "LBN",
+            Self::LBR => "LBR",
+            Self::LBY => "LBY",
+            Self::LCA => "LCA",
+            Self::LIE => "LIE",
+            Self::LKA => "LKA",
+            Self::LSO => "LSO",
+            Self::LTU => "LTU",
+            Self::LUX => "LUX",
+            Self::LVA => "LVA",
+            Self::MAC => "MAC",
+            Self::MAF => "MAF",
+            Self::MAR => "MAR",
+            Self::MCO => "MCO",
+            Self::MDA => "MDA",
+            Self::MDG => "MDG",
+            Self::MDV => "MDV",
+            Self::MEX => "MEX",
+            Self::MHL => "MHL",
+            Self::MKD => "MKD",
+            Self::MLI => "MLI",
+            Self::MLT => "MLT",
+            Self::MMR => "MMR",
+            Self::MNE => "MNE",
+            Self::MNG => "MNG",
+            Self::MNP => "MNP",
+            Self::MOZ => "MOZ",
+            Self::MRT => "MRT",
+            Self::MSR => "MSR",
+            Self::MTQ => "MTQ",
+            Self::MUS => "MUS",
+            Self::MWI => "MWI",
+            Self::MYS => "MYS",
+            Self::MYT => "MYT",
+            Self::NAM => "NAM",
+            Self::NCL => "NCL",
+            Self::NER => "NER",
+            Self::NFK => "NFK",
+            Self::NGA => "NGA",
+            Self::NIC => "NIC",
+            Self::NIU => "NIU",
+            Self::NLD => "NLD",
+            Self::NOR => "NOR",
+            Self::NPL => "NPL",
+            Self::NRU => "NRU",
+            Self::NZL => "NZL",
+            Self::OMN => "OMN",
+            Self::PAK => "PAK",
+            Self::PAN => "PAN",
+            Self::PCN => "PCN",
+            Self::PER => "PER",
+            Self::PHL => "PHL",
+            Self::PLW => "PLW",
+            Self::PNG => "PNG",
+            Self::POL => "POL",
+            Self::PRI => "PRI",
+            Self::PRK => "PRK",
+            Self::PRT => "PRT",
+            Self::PRY => "PRY",
+            Self::PSE => "PSE",
+            Self::PYF => "PYF",
+            Self::QAT => "QAT",
+            Self::REU => "REU",
+            Self::ROU => "ROU",
+            Self::RUS => "RUS",
+            Self::RWA => "RWA",
+            Self::SAU => "SAU",
+            Self::SDN => "SDN",
+            Self::SEN => "SEN",
+            Self::SGP => "SGP",
+            Self::SGS => "SGS",
+            Self::SHN => "SHN",
+            Self::SJM => "SJM",
+            Self::SLB => "SLB",
+            Self::SLE => "SLE",
+            Self::SLV => "SLV",
+            Self::SMR => "SMR",
+            Self::SOM => "SOM",
+            Self::SPM => "SPM",
+            Self::SRB => "SRB",
+            Self::SSD => "SSD",
+            Self::STP => "STP",
+            Self::SUR => "SUR",
+            Self::SVK => "SVK",
+            Self::SVN => "SVN",
+            Self::SWE => "SWE",
+            Self::SWZ => "SWZ",
+            Self::SXM => "SXM",
+            Self::SYC => "SYC",
+            Self::SYR => "SYR",
+            Self::TCA => "TCA",
+            Self::TCD => "TCD",
+            Self::TGO => "TGO",
+            Self::THA => "THA",
+            Self::TJK => "TJK",
+            Self::TKL => "TKL",
+            Self::TKM => "TKM",
+            Self::TLS => "TLS",
+            Self::TON => "TON",
+            Self::TTO => "TTO",
+            Self::TUN => "TUN",
+            Self::TUR => "TUR",
+            Self::TUV => "TUV",
+            Self::TWN => "TWN",
+            Self::TZA => "TZA",
+            Self::UGA => "UGA",
+            Self::UKR => "UKR",
+            Self::UMI => "UMI",
+            Self::URY => "URY",
+            Self::USA => "USA",
+            Self::UZB => "UZB",
+            Self::VAT => "VAT",
+            Self::VCT => "VCT",
+            Self::VEN => "VEN",
+            Self::VGB => "VGB",
+            Self::VIR => "VIR",
+            Self::VNM => "VNM",
+            Self::VUT => "VUT",
+            Self::WLF => "WLF",
+            Self::WSM => "WSM",
+            Self::YEM => "YEM",
+            Self::ZAF => "ZAF",
+            Self::ZMB => "ZMB",
+            Self::ZWE => "ZWE",
+        }
+    }
+}
+
+impl Display for CountryCodeA3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CountryCodeA3 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "ABW" => Ok(Self::ABW),
+            "AFG" => Ok(Self::AFG),
+            "AGO" => Ok(Self::AGO),
+            "AIA" => Ok(Self::AIA),
+            "ALA" => Ok(Self::ALA),
+            "ALB" => Ok(Self::ALB),
+            "AND" => Ok(Self::AND),
+            "ARE" => Ok(Self::ARE),
+            "ARG" => Ok(Self::ARG),
+            "ARM" => Ok(Self::ARM),
+            "ASM" => Ok(Self::ASM),
+            "ATA" => Ok(Self::ATA),
+            "ATF" => Ok(Self::ATF),
+            "ATG" => Ok(Self::ATG),
+            "AUS" => Ok(Self::AUS),
+            "AUT" => Ok(Self::AUT),
+            "AZE" => Ok(Self::AZE),
+            "BDI" => Ok(Self::BDI),
+            "BEL" => Ok(Self::BEL),
+            "BEN" => Ok(Self::BEN),
+            "BES" => Ok(Self::BES),
+            "BFA" => Ok(Self::BFA),
+            "BGD" => Ok(Self::BGD),
+            "BGR" => Ok(Self::BGR),
+            "BHR" => Ok(Self::BHR),
+            "BHS" => Ok(Self::BHS),
+            "BIH" => Ok(Self::BIH),
+            "BLM" => Ok(Self::BLM),
+            "BLR" => Ok(Self::BLR),
+            "BLZ" => Ok(Self::BLZ),
+            "BMU" => Ok(Self::BMU),
+            "BOL" => Ok(Self::BOL),
+            "BRA" => Ok(Self::BRA),
+            "BRB" => Ok(Self::BRB),
+            "BRN" => Ok(Self::BRN),
+            "BTN" => Ok(Self::BTN),
+            "BVT" => Ok(Self::BVT),
+            "BWA" => Ok(Self::BWA),
+            "CAF" => Ok(Self::CAF),
+            "CAN" => Ok(Self::CAN),
+            "CCK" => Ok(Self::CCK),
+            "CHE" => Ok(Self::CHE),
+            "CHL" => Ok(Self::CHL),
+            "CHN" => Ok(Self::CHN),
+            "CIV" => Ok(Self::CIV),
+            "CMR" => Ok(Self::CMR),
+            "COD" => Ok(Self::COD),
+            "COG" => Ok(Self::COG),
+            "COK" => Ok(Self::COK),
+            "COL" => Ok(Self::COL),
+            "COM" => Ok(Self::COM),
+            "CPV" => Ok(Self::CPV),
+            "CRI" => Ok(Self::CRI),
+            "CUB" => Ok(Self::CUB),
+            "CUW" => Ok(Self::CUW),
+            "CXR" => Ok(Self::CXR),
+            "CYM" => Ok(Self::CYM),
+            "CYP" => Ok(Self::CYP),
+            "CZE" => Ok(Self::CZE),
+            "DEU" => Ok(Self::DEU),
+            "DJI" => Ok(Self::DJI),
+            "DMA" => Ok(Self::DMA),
+            "DNK" => Ok(Self::DNK),
+            "DOM" => Ok(Self::DOM),
+            "DZA" => Ok(Self::DZA),
+            "ECU" => Ok(Self::ECU),
+            "EGY" => Ok(Self::EGY),
+            "ERI" => Ok(Self::ERI),
+            "ESH" => Ok(Self::ESH),
+            "ESP" => Ok(Self::ESP),
+            "EST" => Ok(Self::EST),
+            "ETH" => Ok(Self::ETH),
+            "FIN" => Ok(Self::FIN),
+            "FJI" => Ok(Self::FJI),
+            "FLK" => Ok(Self::FLK),
+            "FRA" => Ok(Self::FRA),
+            "FRO" => Ok(Self::FRO),
+            "FSM" => Ok(Self::FSM),
+            "GAB" => Ok(Self::GAB),
+            "GBR" => Ok(Self::GBR),
+            "GEO" => Ok(Self::GEO),
+            "GGY" => Ok(Self::GGY),
+            "GHA" => Ok(Self::GHA),
+            "GIB" => Ok(Self::GIB),
+            "GIN" => Ok(Self::GIN),
+            "GLP" => Ok(Self::GLP),
+            "GMB" => Ok(Self::GMB),
+            "GNB" => Ok(Self::GNB),
+            "GNQ" => Ok(Self::GNQ),
+            "GRC" => Ok(Self::GRC),
+            "GRD" => Ok(Self::GRD),
+            "GRL" => Ok(Self::GRL),
+            "GTM" => Ok(Self::GTM),
+            "GUF" => Ok(Self::GUF),
+            "GUM" => Ok(Self::GUM),
+            "GUY" => Ok(Self::GUY),
+            "HKG" => Ok(Self::HKG),
+            "HMD" => Ok(Self::HMD),
+            "HND" => Ok(Self::HND),
+            "HRV" => Ok(Self::HRV),
+            "HTI" => Ok(Self::HTI),
+            "HUN" => Ok(Self::HUN),
+            "IDN" => Ok(Self::IDN),
+            "IMN" => Ok(Self::IMN),
+            "IND" => Ok(Self::IND),
+            "IOT" => Ok(Self::IOT),
+            "IRL" => Ok(Self::IRL),
+            "IRN" => Ok(Self::IRN),
+            "IRQ" => Ok(Self::IRQ),
+            "ISL" => Ok(Self::ISL),
+            "ISR" => Ok(Self::ISR),
+            "ITA" => Ok(Self::ITA),
+            "JAM" => Ok(Self::JAM),
+            "JEY" => Ok(Self::JEY),
+            "JOR" => Ok(Self::JOR),
+            "JPN" => Ok(Self::JPN),
+            "KAZ" => Ok(Self::KAZ),
+            "KEN" => Ok(Self::KEN),
+            "KGZ" => Ok(Self::KGZ),
+            "KHM" => Ok(Self::KHM),
+            "KIR" => Ok(Self::KIR),
+            "KNA" => Ok(Self::KNA),
+            "KOR" => Ok(Self::KOR),
+            "KWT" => Ok(Self::KWT),
+            "LAO" => Ok(Self::LAO),
+            "LBN" => Ok(Self::LBN),
+            "LBR" => Ok(Self::LBR),
+            "LBY" => Ok(Self::LBY),
+            "LCA" => Ok(Self::LCA),
+            "LIE" => Ok(Self::LIE),
+            "LKA" => Ok(Self::LKA),
+            "LSO" => Ok(Self::LSO),
+            "LTU" => Ok(Self::LTU),
+            "LUX" => Ok(Self::LUX),
+            "LVA" => Ok(Self::LVA),
+            "MAC" => Ok(Self::MAC),
+            "MAF" => Ok(Self::MAF),
+            "MAR" => Ok(Self::MAR),
+            "MCO" => Ok(Self::MCO),
+            "MDA" => Ok(Self::MDA),
+            "MDG" => Ok(Self::MDG),
+            "MDV" => Ok(Self::MDV),
+            "MEX" => Ok(Self::MEX),
+            "MHL" => Ok(Self::MHL),
+            "MKD" => Ok(Self::MKD),
+            "MLI" => Ok(Self::MLI),
+            "MLT" => Ok(Self::MLT),
+            "MMR" => Ok(Self::MMR),
+            "MNE" => Ok(Self::MNE),
+            "MNG" => Ok(Self::MNG),
+            "MNP" => Ok(Self::MNP),
+            "MOZ" => Ok(Self::MOZ),
+            "MRT" => Ok(Self::MRT),
+            "MSR" => Ok(Self::MSR),
+            "MTQ" => Ok(Self::MTQ),
+            "MUS" => Ok(Self::MUS),
+            "MWI" => Ok(Self::MWI),
+            "MYS" => Ok(Self::MYS),
+            "MYT" => Ok(Self::MYT),
+            "NAM" => Ok(Self::NAM),
+            "NCL" => Ok(Self::NCL),
+            "NER" => Ok(Self::NER),
+            "NFK" => Ok(Self::NFK),
+            "NGA" => Ok(Self::NGA),
+            "NIC" => Ok(Self::NIC),
+            "NIU" => Ok(Self::NIU),
+            "NLD" => Ok(Self::NLD),
+            "NOR" => Ok(Self::NOR),
+            "NPL" => Ok(Self::NPL),
+            "NRU" => Ok(Self::NRU),
+            "NZL" => Ok(Self::NZL),
+            "OMN" => Ok(Self::OMN),
+            "PAK" => Ok(Self::PAK),
+            "PAN" => Ok(Self::PAN),
+            "PCN" => Ok(Self::PCN),
+            "PER" => Ok(Self::PER),
+            "PHL" => Ok(Self::PHL),
+            "PLW" => Ok(Self::PLW),
+            "PNG" => Ok(Self::PNG),
+            "POL" => Ok(Self::POL),
+            "PRI" => Ok(Self::PRI),
+            "PRK" => Ok(Self::PRK),
+            "PRT" => Ok(Self::PRT),
+            "PRY" => Ok(Self::PRY),
+            "PSE" => Ok(Self::PSE),
+            "PYF" => Ok(Self::PYF),
+            "QAT" => Ok(Self::QAT),
+            "REU" => Ok(Self::REU),
+            "ROU" => Ok(Self::ROU),
+            "RUS" => Ok(Self::RUS),
+            "RWA" => Ok(Self::RWA),
+            "SAU" => Ok(Self::SAU),
+            "SDN" => Ok(Self::SDN),
+            "SEN" => Ok(Self::SEN),
+            "SGP" => Ok(Self::SGP),
+            "SGS" => Ok(Self::SGS),
+            "SHN" => Ok(Self::SHN),
+            "SJM" => Ok(Self::SJM),
+            "SLB" => Ok(Self::SLB),
+            "SLE" => Ok(Self::SLE),
+            "SLV" => Ok(Self::SLV),
+            "SMR" => Ok(Self::SMR),
+            "SOM" => Ok(Self::SOM),
+            "SPM" => Ok(Self::SPM),
+            "SRB" => Ok(Self::SRB),
+            "SSD" => Ok(Self::SSD),
+            "STP" => Ok(Self::STP),
+            "SUR" => Ok(Self::SUR),
+            "SVK" => Ok(Self::SVK),
+            "SVN" => Ok(Self::SVN),
+            "SWE" => Ok(Self::SWE),
+            "SWZ" => Ok(Self::SWZ),
+            "SXM" => Ok(Self::SXM),
+            "SYC" => Ok(Self::SYC),
+            "SYR" => Ok(Self::SYR),
+            "TCA" => Ok(Self::TCA),
+            "TCD" => Ok(Self::TCD),
+            "TGO" => Ok(Self::TGO),
+            "THA" => Ok(Self::THA),
+            "TJK" => Ok(Self::TJK),
+            "TKL" => Ok(Self::TKL),
+            "TKM" => Ok(Self::TKM),
+            "TLS" => Ok(Self::TLS),
+            "TON" => Ok(Self::TON),
+            "TTO" => Ok(Self::TTO),
+            "TUN" => Ok(Self::TUN),
+            "TUR" => Ok(Self::TUR),
+            "TUV" => Ok(Self::TUV),
+            "TWN" => Ok(Self::TWN),
+            "TZA" => Ok(Self::TZA),
+            "UGA" => Ok(Self::UGA),
+            "UKR" => Ok(Self::UKR),
+            "UMI" => Ok(Self::UMI),
+            "URY" => Ok(Self::URY),
+            "USA" => Ok(Self::USA),
+            "UZB" => Ok(Self::UZB),
+            "VAT" => Ok(Self::VAT),
+            "VCT" => Ok(Self::VCT),
+            "VEN" => Ok(Self::VEN),
+            "VGB" => Ok(Self::VGB),
+            "VIR" => Ok(Self::VIR),
+            "VNM" => Ok(Self::VNM),
+            "VUT" => Ok(Self::VUT),
+            "WLF" => Ok(Self::WLF),
+            "WSM" => Ok(Self::WSM),
+            "YEM" => Ok(Self::YEM),
+            "ZAF" => Ok(Self::ZAF),
+            "ZMB" => Ok(Self::ZMB),
+            "ZWE" => Ok(Self::ZWE),
+            _ => Err(Error::UnknownCode { kind: "country".into(), code: s.to_string() }),
+        }
+    }
+}
+
+impl CountryCodeA3 {
+    pub fn alpha2(&self) -> CountryCode {
+        match self {
+            Self::ABW => CountryCode::AW,
+            Self::AFG => CountryCode::AF,
+            Self::AGO => CountryCode::AO,
+            Self::AIA => CountryCode::AI,
+            Self::ALA => CountryCode::AX,
+            Self::ALB => CountryCode::AL,
+            Self::AND => CountryCode::AD,
+            Self::ARE => CountryCode::AE,
+            Self::ARG => CountryCode::AR,
+            Self::ARM => CountryCode::AM,
+            Self::ASM => CountryCode::AS,
+            Self::ATA => CountryCode::AQ,
+            Self::ATF => CountryCode::TF,
+            Self::ATG => CountryCode::AG,
+            Self::AUS => CountryCode::AU,
+            Self::AUT => CountryCode::AT,
+            Self::AZE => CountryCode::AZ,
+            Self::BDI => CountryCode::BI,
+            Self::BEL => CountryCode::BE,
+            Self::BEN => CountryCode::BJ,
+            Self::BES => CountryCode::BQ,
+            Self::BFA => CountryCode::BF,
+            Self::BGD => CountryCode::BD,
+            Self::BGR => CountryCode::BG,
+            Self::BHR => CountryCode::BH,
+            Self::BHS => CountryCode::BS,
+            Self::BIH => CountryCode::BA,
+            Self::BLM => CountryCode::BL,
+            Self::BLR => CountryCode::BY,
+            Self::BLZ => CountryCode::BZ,
+            Self::BMU => CountryCode::BM,
+            Self::BOL => CountryCode::BO,
+            Self::BRA => CountryCode::BR,
+            Self::BRB => CountryCode::BB,
+            Self::BRN => CountryCode::BN,
+            Self::BTN => CountryCode::BT,
+            Self::BVT => CountryCode::BV,
+            Self::BWA => CountryCode::BW,
+            Self::CAF => CountryCode::CF,
+            Self::CAN => CountryCode::CA,
+            Self::CCK => CountryCode::CC,
+            Self::CHE => CountryCode::CH,
+            Self::CHL => CountryCode::CL,
+            Self::CHN => CountryCode::CN,
+            Self::CIV => CountryCode::CI,
+            Self::CMR => CountryCode::CM,
+            Self::COD => CountryCode::CD,
+            Self::COG => CountryCode::CG,
+            Self::COK => CountryCode::CK,
+            Self::COL => CountryCode::CO,
+            Self::COM => CountryCode::KM,
+            Self::CPV => CountryCode::CV,
+            Self::CRI => CountryCode::CR,
+            Self::CUB => CountryCode::CU,
+            Self::CUW => CountryCode::CW,
+            Self::CXR => CountryCode::CX,
+            Self::CYM => CountryCode::KY,
+            Self::CYP => CountryCode::CY,
+            Self::CZE => CountryCode::CZ,
+            Self::DEU => CountryCode::DE,
+            Self::DJI => CountryCode::DJ,
+            Self::DMA => CountryCode::DM,
+            Self::DNK => CountryCode::DK,
+            Self::DOM => CountryCode::DO,
+            Self::DZA => CountryCode::DZ,
+            Self::ECU => CountryCode::EC,
+            Self::EGY => CountryCode::EG,
+            Self::ERI => CountryCode::ER,
+            Self::ESH => CountryCode::EH,
+            Self::ESP => CountryCode::ES,
+            Self::EST => CountryCode::EE,
+            Self::ETH => CountryCode::ET,
+            Self::FIN => CountryCode::FI,
+            Self::FJI => CountryCode::FJ,
+            Self::FLK => CountryCode::FK,
+            Self::FRA => CountryCode::FR,
+            Self::FRO => CountryCode::FO,
+            Self::FSM => CountryCode::FM,
+            Self::GAB => CountryCode::GA,
+            Self::GBR => CountryCode::GB,
+            Self::GEO => CountryCode::GE,
+            Self::GGY => CountryCode::GG,
+            Self::GHA => CountryCode::GH,
+            Self::GIB => CountryCode::GI,
+            Self::GIN => CountryCode::GN,
+            Self::GLP => CountryCode::GP,
+            Self::GMB => CountryCode::GM,
+            Self::GNB => CountryCode::GW,
+            Self::GNQ => CountryCode::GQ,
+            Self::GRC => CountryCode::GR,
+            Self::GRD => CountryCode::GD,
+            Self::GRL => CountryCode::GL,
+            Self::GTM => CountryCode::GT,
+            Self::GUF => CountryCode::GF,
+            Self::GUM => CountryCode::GU,
+            Self::GUY => CountryCode::GY,
+            Self::HKG => CountryCode::HK,
+            Self::HMD => CountryCode::HM,
+            Self::HND => CountryCode::HN,
+            Self::HRV => CountryCode::HR,
+            Self::HTI => CountryCode::HT,
+            Self::HUN => CountryCode::HU,
+            Self::IDN => CountryCode::ID,
+            Self::IMN => CountryCode::IM,
+            Self::IND => CountryCode::IN,
+            Self::IOT => CountryCode::IO,
+            Self::IRL => CountryCode::IE,
+            Self::IRN => CountryCode::IR,
+            Self::IRQ => CountryCode::IQ,
+            Self::ISL => CountryCode::IS,
+            Self::ISR => CountryCode::IL,
+            Self::ITA => CountryCode::IT,
+            Self::JAM => CountryCode::JM,
+            Self::JEY => CountryCode::JE,
+            Self::JOR => CountryCode::JO,
+            Self::JPN => CountryCode::JP,
+            Self::KAZ => CountryCode::KZ,
+            Self::KEN => CountryCode::KE,
+            Self::KGZ => CountryCode::KG,
+            Self::KHM => CountryCode::KH,
+            Self::KIR => CountryCode::KI,
+            Self::KNA => CountryCode::KN,
+            Self::KOR => CountryCode::KR,
+            Self::KWT => CountryCode::KW,
+            Self::LAO => CountryCode::LA,
+            Self::LBN => CountryCode::LB,
+            Self::LBR => CountryCode::LR,
+            Self::LBY => CountryCode::LY,
+            Self::LCA => CountryCode::LC,
+            Self::LIE => CountryCode::LI,
+            Self::LKA => CountryCode::LK,
+            Self::LSO => CountryCode::LS,
+            Self::LTU => CountryCode::LT,
+            Self::LUX => CountryCode::LU,
+            Self::LVA => CountryCode::LV,
+            Self::MAC => CountryCode::MO,
+            Self::MAF => CountryCode::MF,
+            Self::MAR => CountryCode::MA,
+            Self::MCO => CountryCode::MC,
+            Self::MDA => CountryCode::MD,
+            Self::MDG => CountryCode::MG,
+            Self::MDV => CountryCode::MV,
+            Self::MEX => CountryCode::MX,
+            Self::MHL => CountryCode::MH,
+            Self::MKD => CountryCode::MK,
+            Self::MLI => CountryCode::ML,
+            Self::MLT => CountryCode::MT,
+            Self::MMR => CountryCode::MM,
+            Self::MNE => CountryCode::ME,
+            Self::MNG => CountryCode::MN,
+            Self::MNP => CountryCode::MP,
+            Self::MOZ => CountryCode::MZ,
+            Self::MRT => CountryCode::MR,
+            Self::MSR => CountryCode::MS,
+            Self::MTQ => CountryCode::MQ,
+            Self::MUS => CountryCode::MU,
+            Self::MWI => CountryCode::MW,
+            Self::MYS => CountryCode::MY,
+            Self::MYT => CountryCode::YT,
+            Self::NAM => CountryCode::NA,
+            Self::NCL => CountryCode::NC,
+            Self::NER => CountryCode::NE,
+            Self::NFK => CountryCode::NF,
+            Self::NGA => CountryCode::NG,
+            Self::NIC => CountryCode::NI,
+            Self::NIU => CountryCode::NU,
+            Self::NLD => CountryCode::NL,
+            Self::NOR => CountryCode::NO,
+            Self::NPL => CountryCode::NP,
+            Self::NRU => CountryCode::NR,
+            Self::NZL => CountryCode::NZ,
+            Self::OMN => CountryCode::OM,
+            Self::PAK => CountryCode::PK,
+            Self::PAN => CountryCode::PA,
+            Self::PCN => CountryCode::PN,
+            Self::PER => CountryCode::PE,
+            Self::PHL => CountryCode::PH,
+            Self::PLW => CountryCode::PW,
+            Self::PNG => CountryCode::PG,
+            Self::POL => CountryCode::PL,
+            Self::PRI => CountryCode::PR,
+            Self::PRK => CountryCode::KP,
+            Self::PRT => CountryCode::PT,
+            Self::PRY => CountryCode::PY,
+            Self::PSE => CountryCode::PS,
+            Self::PYF => CountryCode::PF,
+            Self::QAT => CountryCode::QA,
+            Self::REU => CountryCode::RE,
+            Self::ROU => CountryCode::RO,
+            Self::RUS => CountryCode::RU,
+            Self::RWA => CountryCode::RW,
+            Self::SAU => CountryCode::SA,
+            Self::SDN => CountryCode::SD,
+            Self::SEN => CountryCode::SN,
+            Self::SGP => CountryCode::SG,
+            Self::SGS => CountryCode::GS,
+            Self::SHN => CountryCode::SH,
+            Self::SJM => CountryCode::SJ,
+            Self::SLB => CountryCode::SB,
+            Self::SLE => CountryCode::SL,
+            Self::SLV => CountryCode::SV,
+            Self::SMR => CountryCode::SM,
+            Self::SOM => CountryCode::SO,
+            Self::SPM => CountryCode::PM,
+            Self::SRB => CountryCode::RS,
+            Self::SSD => CountryCode::SS,
+            Self::STP => CountryCode::ST,
+            Self::SUR => CountryCode::SR,
+            Self::SVK => CountryCode::SK,
+            Self::SVN => CountryCode::SI,
+            Self::SWE => CountryCode::SE,
+            Self::SWZ => CountryCode::SZ,
+            Self::SXM => CountryCode::SX,
+            Self::SYC => CountryCode::SC,
+            Self::SYR => CountryCode::SY,
+            Self::TCA => CountryCode::TC,
+            Self::TCD => CountryCode::TD,
+            Self::TGO => CountryCode::TG,
+            Self::THA => CountryCode::TH,
+            Self::TJK => CountryCode::TJ,
+            Self::TKL => CountryCode::TK,
+            Self::TKM => CountryCode::TM,
+            Self::TLS => CountryCode::TL,
+            Self::TON => CountryCode::TO,
+            Self::TTO => CountryCode::TT,
+            Self::TUN => CountryCode::TN,
+            Self::TUR => CountryCode::TR,
+            Self::TUV => CountryCode::TV,
+            Self::TWN => CountryCode::TW,
+            Self::TZA => CountryCode::TZ,
+            Self::UGA => CountryCode::UG,
+            Self::UKR => CountryCode::UA,
+            Self::UMI => CountryCode::UM,
+            Self::URY => CountryCode::UY,
+            Self::USA => CountryCode::US,
+            Self::UZB => CountryCode::UZ,
+            Self::VAT => CountryCode::VA,
+            Self::VCT => CountryCode::VC,
+            Self::VEN => CountryCode::VE,
+            Self::VGB => CountryCode::VG,
+            Self::VIR => CountryCode::VI,
+            Self::VNM => CountryCode::VN,
+            Self::VUT => CountryCode::VU,
+            Self::WLF => CountryCode::WF,
+            Self::WSM => CountryCode::WS,
+            Self::YEM => CountryCode::YE,
+            Self::ZAF => CountryCode::ZA,
+            Self::ZMB => CountryCode::ZM,
+            Self::ZWE => CountryCode::ZW,
+        }
+    }
+}
+
+impl From<CountryCodeA3> for CountryCode {
+    fn from(code: CountryCodeA3) -> Self {
+        code.alpha2()
+    }
+}
+
+/// ISO 4217 code of a currency in use
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CurrencyCode {
+    AED,
+    AFN,
+    ALL,
+    AMD,
+    ANG,
+    AOA,
+    ARS,
+    AUD,
+    AWG,
+    AZN,
+    BAM,
+    BBD,
+    BDT,
+    BGN,
+    BHD,
+    BIF,
+    BMD,
+    BND,
+    BOB,
+    BOV,
+    BRL,
+    BSD,
+    BTN,
+    BWP,
+    BYN,
+    BZD,
+    CAD,
+    CDF,
+    CHE,
+    CHF,
+    CHW,
+    CLF,
+    CLP,
+    CNY,
+    COP,
+    COU,
+    CRC,
+    CUC,
+    CUP,
+    CVE,
+    CZK,
+    DJF,
+    DKK,
+    DOP,
+    DZD,
+    EGP,
+    ERN,
+    ETB,
+    EUR,
+    FJD,
+    FKP,
+    GBP,
+    GEL,
+    GHS,
+    GIP,
+    GMD,
+    GNF,
+    GTQ,
+    GYD,
+    HKD,
+    HNL,
+    HTG,
+    HUF,
+    IDR,
+    ILS,
+    INR,
+    IQD,
+    IRR,
+    ISK,
+    JMD,
+    JOD,
+    JPY,
+    KES,
+    KGS,
+    KHR,
+    KMF,
+    KPW,
+    KRW,
+    KWD,
+    KYD,
+    KZT,
+    LAK,
+    LBP,
+    LKR,
+    LRD,
+    LSL,
+    LYD,
+    MAD,
+    MDL,
+    MGA,
+    MKD,
+    MMK,
+    MNT,
+    MOP,
+    MRU,
+    MUR,
+    MVR,
+    MWK,
+    MXN,
+    MXV,
+    MYR,
+    MZN,
+    NAD,
+    NGN,
+    NIO,
+    NOK,
+    NPR,
+    NZD,
+    OMR,
+    PAB,
+    PEN,
+    PGK,
+    PHP,
+    PKR,
+    PLN,
+    PYG,
+    QAR,
+    RON,
+    RSD,
+    RUB,
+    RWF,
+    SAR,
+    SBD,
+    SCR,
+    SDG,
+    SEK,
+    SGD,
+    SHP,
+    SLE,
+    SLL,
+    SOS,
+    SRD,
+    SSP,
+    STN,
+    SVC,
+    SYP,
+    SZL,
+    THB,
+    TJS,
+    TMT,
+    TND,
+    TOP,
+    TRY,
+    TTD,
+    TWD,
+    TZS,
+    UAH,
+    UGX,
+    USD,
+    USN,
+    UYI,
+    UYU,
+    UYW,
+    UZS,
+    VED,
+    VES,
+    VND,
+    VUV,
+    WST,
+    XAF,
+    XAG,
+    XAU,
+    XBA,
+    XBB,
+    XBC,
+    XBD,
+    XCD,
+    XCG,
+    XDR,
+    XOF,
+    XPD,
+    XPF,
+    XPT,
+    XSU,
+    XTS,
+    XUA,
+    XXX,
+    YER,
+    ZAR,
+    ZMW,
+    ZWG,
+    ZWL,
+}
+
+impl CurrencyCode {
+    pub const ALL: [Self; 182] = [
+        Self::AED,
+        Self::AFN,
+        Self::ALL,
+        Self::AMD,
+        Self::ANG,
+        Self::AOA,
+        Self::ARS,
+        Self::AUD,
+        Self::AWG,
+        Self::AZN,
+        Self::BAM,
+        Self::BBD,
+        Self::BDT,
+        Self::BGN,
+        Self::BHD,
+        Self::BIF,
+        Self::BMD,
+        Self::BND,
+        Self::BOB,
+        Self::BOV,
+        Self::BRL,
+        Self::BSD,
+        Self::BTN,
+        Self::BWP,
+        Self::BYN,
+        Self::BZD,
+        Self::CAD,
+        Self::CDF,
+        Self::CHE,
+        Self::CHF,
+        Self::CHW,
+        Self::CLF,
+        Self::CLP,
+        Self::CNY,
+        Self::COP,
+        Self::COU,
+        Self::CRC,
+        Self::CUC,
+        Self::CUP,
+        Self::CVE,
+        Self::CZK,
+        Self::DJF,
+        Self::DKK,
+        Self::DOP,
+        Self::DZD,
+        Self::EGP,
+        Self::ERN,
+        Self::ETB,
+        Self::EUR,
+        Self::FJD,
+        Self::FKP,
+        Self::GBP,
+        Self::GEL,
+        Self::GHS,
+        Self::GIP,
+        Self::GMD,
+        Self::GNF,
+        Self::GTQ,
+        Self::GYD,
+        Self::HKD,
+        Self::HNL,
+        Self::HTG,
+        Self::HUF,
+        Self::IDR,
+        Self::ILS,
+        Self::INR,
+        Self::IQD,
+        Self::IRR,
+        Self::ISK,
+        Self::JMD,
+        Self::JOD,
+        Self::JPY,
+        Self::KES,
+        Self::KGS,
+        Self::KHR,
+        Self::KMF,
+        Self::KPW,
+        Self::KRW,
+        Self::KWD,
+        Self::KYD,
+        Self::KZT,
+        Self::LAK,
+        Self::LBP,
+        Self::LKR,
+        Self::LRD,
+        Self::LSL,
+        Self::LYD,
+        Self::MAD,
+        Self::MDL,
+        Self::MGA,
+        Self::MKD,
+        Self::MMK,
+        Self::MNT,
+        Self::MOP,
+        Self::MRU,
+        Self::MUR,
+        Self::MVR,
+        Self::MWK,
+        Self::MXN,
+        Self::MXV,
+        Self::MYR,
+        Self::MZN,
+        Self::NAD,
+        Self::NGN,
+        Self::NIO,
+        Self::NOK,
+        Self::NPR,
+        Self::NZD,
+        Self::OMR,
+        Self::PAB,
+        Self::PEN,
+        Self::PGK,
+        Self::PHP,
+        Self::PKR,
+        Self::PLN,
+        Self::PYG,
+        Self::QAR,
+        Self::RON,
+        Self::RSD,
+        Self::RUB,
+        Self::RWF,
+        Self::SAR,
+        Self::SBD,
+        Self::SCR,
+        Self::SDG,
+        Self::SEK,
+        Self::SGD,
+        Self::SHP,
+        Self::SLE,
+        Self::SLL,
+        Self::SOS,
+        Self::SRD,
+        Self::SSP,
+        Self::STN,
+        Self::SVC,
+        Self::SYP,
+        Self::SZL,
+        Self::THB,
+        Self::TJS,
+        Self::TMT,
+        Self::TND,
+        Self::TOP,
+        Self::TRY,
+        Self::TTD,
+        Self::TWD,
+        Self::TZS,
+        Self::UAH,
+        Self::UGX,
+        Self::USD,
+        Self::USN,
+        Self::UYI,
+        Self::UYU,
+        Self::UYW,
+        Self::UZS,
+        Self::VED,
+        Self::VES,
+        Self::VND,
+        Self::VUV,
+        Self::WST,
+        Self::XAF,
+        Self::XAG,
+        Self::XAU,
+        Self::XBA,
+        Self::XBB,
+        Self::XBC,
+        Self::XBD,
+        Self::XCD,
+        Self::XCG,
+        Self::XDR,
+        Self::XOF,
+        Self::XPD,
+        Self::XPF,
+        Self::XPT,
+        Self::XSU,
+        Self::XTS,
+        Self::XUA,
+        Self::XXX,
+        Self::YER,
+        Self::ZAR,
+        Self::ZMW,
+        Self::ZWG,
+        Self::ZWL,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AED => "AED",
+            Self::AFN => "AFN",
+            Self::ALL => "ALL",
+            Self::AMD => "AMD",
+            Self::ANG => "ANG",
+            Self::AOA => "AOA",
+            Self::ARS => "ARS",
+            Self::AUD => "AUD",
+            Self::AWG => "AWG",
+            Self::AZN => "AZN",
+            Self::BAM => "BAM",
+            Self::BBD => "BBD",
+            Self::BDT => "BDT",
+            Self::BGN => "BGN",
+            Self::BHD => "BHD",
+            Self::BIF => "BIF",
+            Self::BMD => "BMD",
+            Self::BND => "BND",
+            Self::BOB => "BOB",
+            Self::BOV => "BOV",
+            Self::BRL => "BRL",
+            Self::BSD => "BSD",
+            Self::BTN => "BTN",
+            Self::BWP => "BWP",
+            Self::BYN => "BYN",
+            Self::BZD => "BZD",
+            Self::CAD => "CAD",
+            Self::CDF => "CDF",
+            Self::CHE => "CHE",
+            Self::CHF => "CHF",
+            Self::CHW => "CHW",
+            Self::CLF => "CLF",
+            Self::CLP => "CLP",
+            Self::CNY => "CNY",
+            Self::COP => "COP",
+            Self::COU => "COU",
+            Self::CRC => "CRC",
+            Self::CUC => "CUC",
+            Self::CUP => "CUP",
+            Self::CVE => "CVE",
+            Self::CZK => "CZK",
+            Self::DJF => "DJF",
+            Self::DKK => "DKK",
+            Self::DOP => "DOP",
+            Self::DZD => "DZD",
+            Self::EGP => "EGP",
+            Self::ERN => "ERN",
+            Self::ETB => "ETB",
+            Self::EUR => "EUR",
+            Self::FJD => "FJD",
+            Self::FKP => "FKP",
+            Self::GBP => "GBP",
+            Self::GEL => "GEL",
+            Self::GHS => "GHS",
+            Self::GIP => "GIP",
+            Self::GMD => "GMD",
+            Self::GNF => "GNF",
+            Self::GTQ => "GTQ",
+            Self::GYD => "GYD",
+            Self::HKD => "HKD",
+            Self::HNL => "HNL",
+            Self::HTG => "HTG",
+            Self::HUF => "HUF",
+            Self::IDR => "IDR",
+            Self::ILS => "ILS",
+            Self::INR => "INR",
+            Self::IQD => "IQD",
+            Self::IRR => "IRR",
+            Self::ISK => "ISK",
+            Self::JMD => "JMD",
+            Self::JOD => "JOD",
+            Self::JPY => "JPY",
+            Self::KES => "KES",
+            Self::KGS => "KGS",
+            Self::KHR => "KHR",
+            Self::KMF => "KMF",
+            Self::KPW => "KPW",
+            Self::KRW => "KRW",
+            Self::KWD => "KWD",
+            Self::KYD => "KYD",
+            Self::KZT => "KZT",
+            Self::LAK => "LAK",
+            Self::LBP => "LBP",
+            Self::LKR => "LKR",
+            Self::LRD => "LRD",
+            Self::LSL => "LSL",
+            Self::LYD => "LYD",
+            Self::MAD => "MAD",
+            Self::MDL => "MDL",
+            Self::MGA => "MGA",
+            Self::MKD => "MKD",
+            Self::MMK => "MMK",
+            Self::MNT => "MNT",
+            Self::MOP => "MOP",
+            Self::MRU => "MRU",
+            Self::MUR => "MUR",
+            Self::MVR => "MVR",
+            Self::MWK => "MWK",
+            Self::MXN => "MXN",
+            Self::MXV => "MXV",
+            Self::MYR => "MYR",
+            Self::MZN => "MZN",
+            Self::NAD => "NAD",
+            Self::NGN => "NGN",
+            Self::NIO => "NIO",
+            Self::NOK => "NOK",
+            Self::NPR => "NPR",
+            Self::NZD => "NZD",
+            Self::OMR => "OMR",
+            Self::PAB => "PAB",
+            Self::PEN => "PEN",
+            Self::PGK => "PGK",
+            Self::PHP => "PHP",
+            Self::PKR => "PKR",
+            Self::PLN => "PLN",
+            Self::PYG => "PYG",
+            Self::QAR => "QAR",
+            Self::RON => "RON",
+            Self::RSD => "RSD",
+            Self::RUB => "RUB",
+            Self::RWF => "RWF",
+            Self::SAR => "SAR",
+            Self::SBD => "SBD",
+            Self::SCR => "SCR",
+            Self::SDG => "SDG",
+            Self::SEK => "SEK",
+            Self::SGD => "SGD",
+            Self::SHP => "SHP",
+            Self::SLE => "SLE",
+            Self::SLL => "SLL",
+            Self::SOS => "SOS",
+            Self::SRD => "SRD",
+            Self::SSP => "SSP",
+            Self::STN => "STN",
+            Self::SVC => "SVC",
+            Self::SYP => "SYP",
+            Self::SZL => "SZL",
+            Self::THB => "THB",
+            Self::TJS => "TJS",
+            Self::TMT => "TMT",
+            Self::TND => "TND",
+            Self::TOP => "TOP",
+            Self::TRY => "TRY",
+            Self::TTD => "TTD",
+            Self::TWD => "TWD",
+            Self::TZS => "TZS",
+            Self::UAH => "UAH",
+            Self::UGX => "UGX",
+            Self::USD => "USD",
+            Self::USN => "USN",
+            Self::UYI => "UYI",
+            Self::UYU => "UYU",
+            Self::UYW => "UYW",
+            Self::UZS => "UZS",
+            Self::VED => "VED",
+            Self::VES => "VES",
+            Self::VND => "VND",
+            Self::VUV => "VUV",
+            Self::WST => "WST",
+            Self::XAF => "XAF",
+            Self::XAG => "XAG",
+            Self::XAU => "XAU",
+            Self::XBA => "XBA",
+            Self::XBB => "XBB",
+            Self::XBC => "XBC",
+            Self::XBD => "XBD",
+            Self::XCD => "XCD",
+            Self::XCG => "XCG",
+            Self::XDR => "XDR",
+            Self::XOF => "XOF",
+            Self::XPD => "XPD",
+            Self::XPF => "XPF",
+            Self::XPT => "XPT",
+            Self::XSU => "XSU",
+            Self::XTS => "XTS",
+            Self::XUA => "XUA",
+            Self::XXX => "XXX",
+            Self::YER => "YER",
+            Self::ZAR => "ZAR",
+            Self::ZMW => "ZMW",
+            Self::ZWG => "ZWG",
+            Self::ZWL => "ZWL",
+        }
+    }
+}
+
+impl Display for CurrencyCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "AED" => Ok(Self::AED),
+            "AFN" => Ok(Self::AFN),
+            "ALL" => Ok(Self::ALL),
+            "AMD" => Ok(Self::AMD),
+            "ANG" => Ok(Self::ANG),
+            "AOA" => Ok(Self::AOA),
+            "ARS" => Ok(Self::ARS),
+            "AUD" => Ok(Self::AUD),
+            "AWG" => Ok(Self::AWG),
+            "AZN" => Ok(Self::AZN),
+            "BAM" => Ok(Self::BAM),
+            "BBD" => Ok(Self::BBD),
+            "BDT" => Ok(Self::BDT),
+            "BGN" => Ok(Self::BGN),
+            "BHD" => Ok(Self::BHD),
+            "BIF" => Ok(Self::BIF),
+            "BMD" => Ok(Self::BMD),
+            "BND" => Ok(Self::BND),
+            "BOB" => Ok(Self::BOB),
+            "BOV" => Ok(Self::BOV),
+            "BRL" => Ok(Self::BRL),
+            "BSD" => Ok(Self::BSD),
+            "BTN" => Ok(Self::BTN),
+            "BWP" => Ok(Self::BWP),
+            "BYN" => Ok(Self::BYN),
+            "BZD" => Ok(Self::BZD),
+            "CAD" => Ok(Self::CAD),
+            "CDF" => Ok(Self::CDF),
+            "CHE" => Ok(Self::CHE),
+            "CHF" => Ok(Self::CHF),
+            "CHW" => Ok(Self::CHW),
+            "CLF" => Ok(Self::CLF),
+            "CLP" => Ok(Self::CLP),
+            "CNY" => Ok(Self::CNY),
+            "COP" => Ok(Self::COP),
+            "COU" => Ok(Self::COU),
+            "CRC" => Ok(Self::CRC),
+            "CUC" => Ok(Self::CUC),
+            "CUP" => Ok(Self::CUP),
+            "CVE" => Ok(Self::CVE),
+            "CZK" => Ok(Self::CZK),
+            "DJF" => Ok(Self::DJF),
+            "DKK" => Ok(Self::DKK),
+            "DOP" => Ok(Self::DOP),
+            "DZD" => Ok(Self::DZD),
+            "EGP" => Ok(Self::EGP),
+            "ERN" => Ok(Self::ERN),
+            "ETB" => Ok(Self::ETB),
+            "EUR" => Ok(Self::EUR),
+            "FJD" => Ok(Self::FJD),
+            "FKP" => Ok(Self::FKP),
+            "GBP" => Ok(Self::GBP),
+            "GEL" => Ok(Self::GEL),
+            "GHS" => Ok(Self::GHS),
+            "GIP" => Ok(Self::GIP),
+            "GMD" => Ok(Self::GMD),
+            "GNF" => Ok(Self::GNF),
+            "GTQ" => Ok(Self::GTQ),
+            "GYD" => Ok(Self::GYD),
+            "HKD" => Ok(Self::HKD),
+            "HNL" => Ok(Self::HNL),
+            "HTG" => Ok(Self::HTG),
+            "HUF" => Ok(Self::HUF),
+            "IDR" => Ok(Self::IDR),
+            "ILS" => Ok(Self::ILS),
+            "INR" => Ok(Self::INR),
+            "IQD" => Ok(Self::IQD),
+            "IRR" => Ok(Self::IRR),
+            "ISK" => Ok(Self::ISK),
+            "JMD" => Ok(Self::JMD),
+            "JOD" => Ok(Self::JOD),
+            "JPY" => Ok(Self::JPY),
+            "KES" => Ok(Self::KES),
+            "KGS" => Ok(Self::KGS),
+            "KHR" => Ok(Self::KHR),
+            "KMF" => Ok(Self::KMF),
+            "KPW" => Ok(Self::KPW),
+            "KRW" => Ok(Self::KRW),
+            "KWD" => Ok(Self::KWD),
+            "KYD" => Ok(Self::KYD),
+            "KZT" => Ok(Self::KZT),
+            "LAK" => Ok(Self::LAK),
+            "LBP" => Ok(Self::LBP),
+            "LKR" => Ok(Self::LKR),
+            "LRD" => Ok(Self::LRD),
+            "LSL" => Ok(Self::LSL),
+            "LYD" => Ok(Self::LYD),
+            "MAD" => Ok(Self::MAD),
+            "MDL" => Ok(Self::MDL),
+            "MGA" => Ok(Self::MGA),
+            "MKD" => Ok(Self::MKD),
+            "MMK" => Ok(Self::MMK),
+            "MNT" => Ok(Self::MNT),
+            "MOP" => Ok(Self::MOP),
+            "MRU" => Ok(Self::MRU),
+            "MUR" => Ok(Self::MUR),
+            "MVR" => Ok(Self::MVR),
+            "MWK" => Ok(Self::MWK),
+            "MXN" => Ok(Self::MXN),
+            "MXV" => Ok(Self::MXV),
+            "MYR" => Ok(Self::MYR),
+            "MZN" => Ok(Self::MZN),
+            "NAD" => Ok(Self::NAD),
+            "NGN" => Ok(Self::NGN),
+            "NIO" => Ok(Self::NIO),
+            "NOK" => Ok(Self::NOK),
+            "NPR" => Ok(Self::NPR),
+            "NZD" => Ok(Self::NZD),
+            "OMR" => Ok(Self::OMR),
+            "PAB" => Ok(Self::PAB),
+            "PEN" => Ok(Self::PEN),
+            "PGK" => Ok(Self::PGK),
+            "PHP" => Ok(Self::PHP),
+            "PKR" => Ok(Self::PKR),
+            "PLN" => Ok(Self::PLN),
+            "PYG" => Ok(Self::PYG),
+            "QAR" => Ok(Self::QAR),
+            "RON" => Ok(Self::RON),
+            "RSD" => Ok(Self::RSD),
+            "RUB" => Ok(Self::RUB),
+            "RWF" => Ok(Self::RWF),
+            "SAR" => Ok(Self::SAR),
+            "SBD" => Ok(Self::SBD),
+            "SCR" => Ok(Self::SCR),
+            "SDG" => Ok(Self::SDG),
+            "SEK" => Ok(Self::SEK),
+            "SGD" => Ok(Self::SGD),
+            "SHP" => Ok(Self::SHP),
+            "SLE" => Ok(Self::SLE),
+            "SLL" => Ok(Self::SLL),
+            "SOS" => Ok(Self::SOS),
+            "SRD" => Ok(Self::SRD),
+            "SSP" => Ok(Self::SSP),
+            "STN" => Ok(Self::STN),
+            "SVC" => Ok(Self::SVC),
+            "SYP" => Ok(Self::SYP),
+            "SZL" => Ok(Self::SZL),
+            "THB" => Ok(Self::THB),
+            "TJS" => Ok(Self::TJS),
+            "TMT" => Ok(Self::TMT),
+            "TND" => Ok(Self::TND),
+            "TOP" => Ok(Self::TOP),
+            "TRY" => Ok(Self::TRY),
+            "TTD" => Ok(Self::TTD),
+            "TWD" => Ok(Self::TWD),
+            "TZS" => Ok(Self::TZS),
+            "UAH" => Ok(Self::UAH),
+            "UGX" => Ok(Self::UGX),
+            "USD" => Ok(Self::USD),
+            "USN" => Ok(Self::USN),
+            "UYI" => Ok(Self::UYI),
+            "UYU" => Ok(Self::UYU),
+            "UYW" => Ok(Self::UYW),
+            "UZS" => Ok(Self::UZS),
+            "VED" => Ok(Self::VED),
+            "VES" => Ok(Self::VES),
+            "VND" => Ok(Self::VND),
+            "VUV" => Ok(Self::VUV),
+            "WST" => Ok(Self::WST),
+            "XAF" => Ok(Self::XAF),
+            "XAG" => Ok(Self::XAG),
+            "XAU" => Ok(Self::XAU),
+            "XBA" => Ok(Self::XBA),
+            "XBB" => Ok(Self::XBB),
+            "XBC" => Ok(Self::XBC),
+            "XBD" => Ok(Self::XBD),
+            "XCD" => Ok(Self::XCD),
+            "XCG" => Ok(Self::XCG),
+            "XDR" => Ok(Self::XDR),
+            "XOF" => Ok(Self::XOF),
+            "XPD" => Ok(Self::XPD),
+            "XPF" => Ok(Self::XPF),
+            "XPT" => Ok(Self::XPT),
+            "XSU" => Ok(Self::XSU),
+            "XTS" => Ok(Self::XTS),
+            "XUA" => Ok(Self::XUA),
+            "XXX" => Ok(Self::XXX),
+            "YER" => Ok(Self::YER),
+            "ZAR" => Ok(Self::ZAR),
+            "ZMW" => Ok(Self::ZMW),
+            "ZWG" => Ok(Self::ZWG),
+            "ZWL" => Ok(Self::ZWL),
+            _ => Err(Error::UnknownCode { kind: "currency".into(), code: s.to_string() }),
+        }
+    }
+}
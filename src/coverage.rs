@@ -0,0 +1,254 @@
+//! How complete the built countries are, per field and per country
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs::write;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::Country;
+use crate::error::{Error, Result};
+use crate::merge::has_value;
+
+/// Fields a complete country has a value for
+pub const FIELDS: &[&str] = &["capital", "iso_3166_1_a3", "iso_3166_1_numeric", "tld", "flag",
+    "currency", "time", "calling_code", "endonyms", "languages", "locales"];
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq)]
+pub struct FieldCoverage {
+    /// Countries having a value
+    pub present: usize,
+    pub total: usize,
+}
+
+impl FieldCoverage {
+    pub fn percent(&self) -> f64 {
+        match self.total {
+            0 => 100.0,
+            n => self.present as f64 * 100.0 / n as f64,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct Coverage {
+    pub fields: BTreeMap<String, FieldCoverage>,
+    /// Fields each incomplete country is missing, complete ones are left out
+    pub missing: BTreeMap<String, Vec<String>>,
+}
+
+impl Coverage {
+    pub fn new<K: Display>(countries: &BTreeMap<K, Country>) -> Self {
+        let mut coverage = Self::default();
+
+        for f in FIELDS {
+            coverage.fields.insert(f.to_string(), FieldCoverage { present: 0, total: countries.len() });
+        }
+
+        for (k, c) in countries.iter() {
+            let missing = c.missing_fields();
+
+            for (f, x) in coverage.fields.iter_mut() {
+                if ! missing.contains(&f.as_str()) {
+                    x.present += 1;
+                }
+            }
+
+            if ! missing.is_empty() {
+                coverage.missing.insert(k.to_string(), missing.iter().map(|s|s.to_string()).collect());
+            }
+        }
+
+        coverage
+    }
+
+    /// Share of every field of every country having a value
+    pub fn percent(&self) -> f64 {
+        let present = self.fields.values().map(|f|f.present).sum::<usize>();
+        let total = self.fields.values().map(|f|f.total).sum::<usize>();
+
+        FieldCoverage { present, total }.percent()
+    }
+
+    /// Fields whose coverage changed since the previous build, with the coverage
+    /// then and now
+    pub fn changes(&self, previous: &Coverage) -> Vec<(String, f64, f64)> {
+        self.fields.iter()
+            .map(|(k, f)|(k.to_owned(), previous.fields.get(k).map(|p|p.percent()).unwrap_or(0.0),
+                f.percent()))
+            .filter(|(_, p, c)|(p - c).abs() >= 0.05)
+            .collect()
+    }
+
+    /// Table of the countries missing something and what they miss
+    pub fn missing_table(&self) -> String {
+        let mut table = format!("{:<7} missing", "country");
+
+        for (k, v) in self.missing.iter() {
+            table.push_str(&format!("\n{:<7} {}", k, v.join(", ")));
+        }
+
+        table
+    }
+
+    pub fn path_for(out: &Path) -> PathBuf {
+        let stem = out.file_stem().unwrap_or_default().to_string_lossy();
+
+        out.with_file_name(format!("{}.coverage.json", stem))
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::Json { url: path.display().to_string(), source: e })?;
+
+        write(path, json).map_err(|e| Error::File { path: path.to_owned(), source: e })
+    }
+}
+
+/// Table of coverage per field
+impl Display for Coverage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<20} {:>9} {:>8}", "field", "countries", "coverage")?;
+
+        for (k, c) in self.fields.iter() {
+            writeln!(f, "{:<20} {:>9} {:>7.1}%", k, format!("{}/{}", c.present, c.total),
+                c.percent())?;
+        }
+
+        write!(f, "{:<20} {:>9} {:>7.1}%", "all", "", self.percent())
+    }
+}
+
+/// Minimum coverage the build must reach. `90` applies to every field,
+/// `flag=99` to just the one.
+#[derive(Debug, Default, Clone)]
+pub struct CoverageGate {
+    pub default: Option<f64>,
+    pub fields: BTreeMap<String, f64>,
+}
+
+impl CoverageGate {
+    /// Gate of `90` and `flag=99` like arguments, later ones override earlier ones
+    pub fn from_args(args: &[String]) -> std::result::Result<Self, String> {
+        let mut gate = Self::default();
+
+        for a in args {
+            match a.split_once('=') {
+                Some((f, p)) => {
+                    if ! FIELDS.contains(&f) {
+                        return Err(format!("Unknown field {}, expected one of {}", f,
+                            FIELDS.join(", ")))
+                    }
+
+                    gate.fields.insert(f.to_string(), percent(p)?);
+                },
+                None => gate.default = Some(percent(a)?),
+            }
+        }
+
+        Ok(gate)
+    }
+
+    /// Fields below their minimum, with the coverage and the minimum
+    pub fn check(&self, coverage: &Coverage) -> Vec<(String, f64, f64)> {
+        coverage.fields.iter()
+            .filter_map(|(k, f)|self.fields.get(k).or(self.default.as_ref())
+                .map(|m|(k.to_owned(), f.percent(), *m)))
+            .filter(|(_, p, m)|p < m)
+            .collect()
+    }
+}
+
+fn percent(s: &str) -> std::result::Result<f64, String> {
+    match s.trim().trim_end_matches('%').parse::<f64>() {
+        Ok(p) if (0.0..=100.0).contains(&p) => Ok(p),
+        _ => Err(format!("Coverage {} is not a percentage", s)),
+    }
+}
+
+impl Country {
+    /// Fields of `FIELDS` the country has no value for
+    pub fn missing_fields(&self) -> Vec<&'static str> {
+        let value = serde_json::to_value(self).unwrap_or_default();
+
+        FIELDS.iter()
+            .filter(|f|! value.get(**f).is_some_and(has_value))
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(a: &[&str]) -> Vec<String> {
+        a.iter().map(|s|s.to_string()).collect()
+    }
+
+    fn coverage(fields: &[(&str, usize)]) -> Coverage {
+        Coverage {
+            fields: fields.iter()
+                .map(|(f, n)|(f.to_string(), FieldCoverage { present: *n, total: 100 }))
+                .collect(),
+            ..Coverage::default()
+        }
+    }
+
+    #[test]
+    fn gate_from_args() {
+        let gate = CoverageGate::from_args(&args(&["90", "flag=99", "tld=95%"])).unwrap();
+
+        assert_eq!(gate.default, Some(90.0));
+        assert_eq!(gate.fields["flag"], 99.0);
+        assert_eq!(gate.fields["tld"], 95.0);
+
+        // Later ones override earlier ones
+        let gate = CoverageGate::from_args(&args(&["90", "80", "flag=99", "flag=50"])).unwrap();
+
+        assert_eq!(gate.default, Some(80.0));
+        assert_eq!(gate.fields["flag"], 50.0);
+        assert!(CoverageGate::from_args(&[]).unwrap().default.is_none());
+    }
+
+    #[test]
+    fn gate_refuses_unknown_fields_and_percentages() {
+        let e = CoverageGate::from_args(&args(&["exonym=90"])).unwrap_err();
+
+        assert!(e.contains("Unknown field exonym"));
+        assert!(CoverageGate::from_args(&args(&["flag=101"])).is_err());
+        assert!(CoverageGate::from_args(&args(&["-1"])).is_err());
+        assert!(CoverageGate::from_args(&args(&["most"])).is_err());
+    }
+
+    #[test]
+    fn gate_checks_fields_below_their_minimum() {
+        let coverage = coverage(&[("flag", 98), ("tld", 95), ("time", 85)]);
+
+        let gate = CoverageGate::from_args(&args(&["90", "flag=99"])).unwrap();
+
+        assert_eq!(gate.check(&coverage), vec![
+            ("flag".to_string(), 98.0, 99.0), ("time".to_string(), 85.0, 90.0)
+        ]);
+
+        // Fields without a minimum of their own are not checked without a default
+        let gate = CoverageGate::from_args(&args(&["tld=95"])).unwrap();
+
+        assert!(gate.check(&coverage).is_empty());
+        assert!(CoverageGate::default().check(&coverage).is_empty());
+    }
+
+    #[test]
+    fn counts_missing_fields_of_countries() {
+        let countries = [
+            ("FI".to_string(), Country { capital: Some("Helsinki".into()), ..Country::default() }),
+            ("SE".to_string(), Country::default()),
+        ].into_iter().collect::<BTreeMap<_, _>>();
+
+        let coverage = Coverage::new(&countries);
+
+        assert_eq!(coverage.fields["capital"], FieldCoverage { present: 1, total: 2 });
+        assert_eq!(coverage.fields["capital"].percent(), 50.0);
+        assert_eq!(coverage.missing["SE"].len(), FIELDS.len());
+        assert!(! coverage.missing["FI"].contains(&"capital".to_string()));
+    }
+}
@@ -0,0 +1,416 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+use serde::{Deserialize, Serialize};
+
+use crate::{Country, Currency, CurrencyCode, Symbol, SymbolKind};
+
+const ISO_4217: &str = include_str!("../data/currency_codes.json");
+const WITHDRAWN: &str = include_str!("../data/withdrawn_currencies.json");
+
+static LISTED: OnceLock<BTreeMap<String, ListedCurrency>> = OnceLock::new();
+static WITHDRAWALS: OnceLock<Vec<Withdrawal>> = OnceLock::new();
+
+/// Currency on the ISO 4217 list
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ListedCurrency {
+    pub name: String,
+    pub numeric_code: u16,
+    /// `None` for codes without one, like gold
+    pub minor_unit: Option<u8>,
+}
+
+/// Currency a country no longer uses, as on the historic ISO 4217 list, with the
+/// code that replaced it there
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Withdrawal {
+    /// Alpha-2 code of the country
+    pub country: String,
+    pub iso_4217: String,
+    /// Year and month, `2002-03`
+    pub withdrawn: String,
+    pub replaced_by: String,
+}
+
+/// Currencies on the bundled ISO 4217 list by their codes
+pub fn iso_4217() -> &'static BTreeMap<String, ListedCurrency> {
+    LISTED.get_or_init(||serde_json::from_str(ISO_4217)
+        .expect("Bundled currency codes are not valid JSON"))
+}
+
+/// Withdrawn currencies bundled in `data/withdrawn_currencies.json`. Sources
+/// still give some of them as current ones.
+pub fn withdrawals() -> &'static [Withdrawal] {
+    WITHDRAWALS.get_or_init(||serde_json::from_str(WITHDRAWN)
+        .expect("Bundled withdrawn currencies are not valid JSON"))
+}
+
+/// Current currencies of the countries with codes on the ISO 4217 list, first one
+/// of each code
+pub fn currencies_in_use<K>(countries: &BTreeMap<K, Country>) -> BTreeMap<String, Currency> {
+    let mut in_use = BTreeMap::new();
+
+    for c in countries.values().filter_map(|c|c.currency.as_ref()) {
+        if let Some(code) = c.iso_4217.as_ref().filter(|s|iso_4217().contains_key(*s)) {
+            in_use.entry(code.to_owned()).or_insert_with(||c.to_owned());
+        }
+    }
+
+    in_use
+}
+
+impl Currency {
+    /// ISO 4217 code as `CurrencyCode`, `None` for withdrawn currencies and ones
+    /// newer than the enum
+    pub fn code(&self) -> Option<CurrencyCode> {
+        self.iso_4217.as_ref()?.parse().ok()
+    }
+
+    /// Read the free text symbol of the sources (`Dh or Dhs (pl.)`) into a list of
+    /// symbols. Symbol field is left holding the primary one.
+    pub fn resolve_symbols(&mut self) {
+        if self.symbols.is_some() {
+            return
+        }
+
+        let symbols = match self.symbol {
+            Some(ref s) => parse_symbols(s),
+            None => return,
+        };
+
+        self.symbol = symbols.iter()
+            .find(|s|s.kind == SymbolKind::Primary)
+            .map(|s|s.symbol.to_owned());
+
+        self.symbols = match symbols.is_empty() {
+            true => None,
+            false => Some(symbols),
+        };
+    }
+
+    /// Currency of the bundled ISO 4217 list with its name, numeric code and minor
+    /// unit
+    pub fn listed(code: &str) -> Option<Self> {
+        let l = iso_4217().get(code)?;
+
+        Some(Self {
+            name: l.name.to_owned(), iso_4217: Some(code.to_string()),
+            numeric_code: Some(l.numeric_code), minor_unit: l.minor_unit, ..Self::default()
+        })
+    }
+
+    /// Numeric code and minor unit of the bundled ISO 4217 list when the sources
+    /// gave none
+    pub fn resolve_listed(&mut self) {
+        let l = match self.iso_4217.as_ref().and_then(|c|iso_4217().get(c)) {
+            Some(l) => l,
+            None => return,
+        };
+
+        if self.numeric_code.is_none() {
+            self.numeric_code = Some(l.numeric_code);
+        }
+
+        if self.minor_unit.is_none() {
+            self.minor_unit = l.minor_unit;
+        }
+    }
+
+    /// Values of the currency that don't add up
+    pub fn validate(&self) -> Vec<String> {
+        let mut issues = vec![];
+
+        if let Some(ref c) = self.iso_4217 {
+            if c.len() != 3 || ! c.chars().all(|c|c.is_ascii_uppercase()) {
+                issues.push(format!("code {} is not three uppercase letters", c));
+            }
+        }
+
+        if let Some(n) = self.numeric_code {
+            if n == 0 || n > 999 {
+                issues.push(format!("numeric code {} is not a three digit number", n));
+            }
+        }
+
+        if let Some(m) = self.minor_unit {
+            if m > 4 {
+                issues.push(format!("minor unit of {} decimals is more than ISO 4217 has", m));
+            }
+
+            if let Some(f) = self.fractions_in_unit {
+                if 10_i64.checked_pow(m as u32).ne(&Some(f as i64)) {
+                    issues.push(format!("minor unit of {} decimals does not match {} \
+                        fractions in unit", m, f));
+                }
+            }
+        }
+
+        match (&self.fraction, self.fractions_in_unit) {
+            (Some(f), None) => issues.push(format!("fraction {} has no fractions in unit", f)),
+            (None, Some(f)) => issues.push(format!("{} fractions in unit have no name", f)),
+            _ => (),
+        }
+
+        if let Some(ref v) = self.symbols {
+            let primary = v.iter().find(|s|s.kind == SymbolKind::Primary);
+
+            if primary.map(|s|&s.symbol).ne(&self.symbol.as_ref()) {
+                issues.push(format!("symbol {:?} is not the primary one of {:?}", self.symbol, v));
+            }
+        }
+
+        issues
+    }
+}
+
+impl Country {
+    /// Order historical currencies by their withdrawal, latest first, and drop the
+    /// ones that are still in use.
+    pub fn tidy_currencies(&mut self) {
+        let mut v = match self.historical_currencies.take() {
+            Some(v) => v,
+            None => return,
+        };
+
+        let current = self.currency.as_ref().and_then(|c|c.iso_4217.to_owned());
+
+        v.retain(|c|c.iso_4217.is_none() || c.iso_4217.ne(&current));
+        v.sort_by(|a, b|b.withdrawn.cmp(&a.withdrawn).then(a.name.cmp(&b.name)));
+        v.dedup_by(|a, b|a.iso_4217.eq(&b.iso_4217) && a.withdrawn.eq(&b.withdrawn));
+
+        if ! v.is_empty() {
+            self.historical_currencies = Some(v);
+        }
+    }
+
+    /// Current currency that's not on the bundled ISO 4217 list has been withdrawn.
+    /// When `withdrawals` tells what replaced it in the country it's moved to the
+    /// historical currencies with its withdrawal date and the replacement becomes
+    /// the current one, with the values of the same currency in use elsewhere if
+    /// there are any. Returns codes of the withdrawn and the replacing currency.
+    pub fn retire_unlisted_currency(&mut self, in_use: &BTreeMap<String, Currency>)
+    -> Option<(String, String)> {
+        let code = self.currency.as_ref()?.iso_4217.to_owned()?;
+
+        if iso_4217().contains_key(&code) {
+            return None
+        }
+
+        let w = withdrawals().iter()
+            .find(|w|w.country.eq(&self.iso_3166_1_a2) && w.iso_4217.eq(&code))?;
+
+        let replacement = match in_use.get(&w.replaced_by) {
+            Some(c) => c.to_owned(),
+            None => Currency::listed(&w.replaced_by)?,
+        };
+
+        let mut currency = self.currency.replace(replacement)?;
+        let historical = self.historical_currencies.get_or_insert_with(Vec::new);
+
+        // Sources may know of the withdrawal already
+        match historical.iter_mut().find(|c|c.iso_4217.as_ref() == Some(&code)) {
+            Some(c) => if c.withdrawn.is_none() {
+                c.withdrawn = Some(w.withdrawn.to_owned());
+            },
+            None => {
+                currency.withdrawn = Some(w.withdrawn.to_owned());
+                historical.push(currency);
+            },
+        }
+
+        Some((code, w.replaced_by.to_owned()))
+    }
+
+    /// Currencies the country has used, current one first
+    pub fn currencies(&self) -> Vec<&Currency> {
+        self.currency.iter()
+            .chain(self.historical_currencies.iter().flatten())
+            .collect()
+    }
+}
+
+/// Check currencies of every country and that currencies sharing a code have the
+/// same numeric code and minor unit everywhere.
+pub fn validate_currencies<K: Display>(countries: &BTreeMap<K, Country>) -> Vec<String> {
+    let mut issues = vec![];
+    let mut seen = BTreeMap::<&str, (&K, &Currency)>::new();
+
+    for (k, c) in countries.iter() {
+        let currency = match c.currency {
+            Some(ref c) => c,
+            None => continue,
+        };
+
+        for i in currency.validate() {
+            issues.push(format!("Currency {} of {}: {}", currency.name, k, i));
+        }
+
+        let code = match currency.iso_4217 {
+            Some(ref s) => s.as_str(),
+            None => continue,
+        };
+
+        match seen.get(code) {
+            Some((other, o)) => {
+                let numeric = o.numeric_code.is_some() && currency.numeric_code.is_some()
+                    && o.numeric_code.ne(&currency.numeric_code);
+                let minor = o.minor_unit.is_some() && currency.minor_unit.is_some()
+                    && o.minor_unit.ne(&currency.minor_unit);
+
+                if numeric || minor {
+                    issues.push(format!("Currency {} of {} and {} differ on numeric code \
+                        ({:?}, {:?}) or minor unit ({:?}, {:?})", code, other, k, o.numeric_code,
+                        currency.numeric_code, o.minor_unit, currency.minor_unit));
+                }
+            },
+            None => {
+                seen.insert(code, (k, currency));
+            },
+        }
+    }
+
+    issues
+}
+
+/// Symbols separated with `or`, plural ones marked with `(pl.)`
+pub fn parse_symbols(s: &str) -> Vec<Symbol> {
+    let mut symbols = vec![];
+
+    // Direction marks and such tag along with right to left symbols
+    let s = s.chars()
+        .filter(|c|! matches!(c, '\u{200B}'..='\u{200F}' | '\u{202A}'..='\u{202E}'))
+        .collect::<String>();
+
+    for part in s.split(" or ") {
+        let (part, plural) = match part.trim().strip_suffix("(pl.)") {
+            Some(p) => (p.trim(), true),
+            None => (part.trim(), false),
+        };
+
+        // Leftovers of footnotes and brackets are no symbols
+        if ! part.chars().any(|c|! "()[]./,-".contains(c)) {
+            continue;
+        }
+
+        let kind = match plural {
+            true => SymbolKind::Plural,
+            false => SymbolKind::Primary,
+        };
+
+        symbols.push(Symbol { symbol: part.to_string(), kind });
+
+        // Letters before the sign tell the currency apart from others using it
+        let sign = part.chars().last().filter(|c|is_currency_sign(*c));
+        let letters = part.chars().count() > 1
+            && part.chars().rev().skip(1).all(|c|c.is_alphabetic());
+
+        if let (Some(c), true, false) = (sign, letters, plural) {
+            symbols.push(Symbol { symbol: c.to_string(), kind: SymbolKind::Narrow });
+        }
+    }
+
+    symbols
+}
+
+fn is_currency_sign(c: char) -> bool {
+    matches!(c, '$' | '¢' | '£' | '¤' | '¥' | '֏' | '؋' | '৳' | '฿' | '៛' | '\u{20A0}'..='\u{20CF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn currency(code: &str, withdrawn: Option<&str>) -> Currency {
+        Currency {
+            name: code.into(), iso_4217: Some(code.into()),
+            withdrawn: withdrawn.map(|s|s.to_string()), ..Currency::default()
+        }
+    }
+
+    fn country(a2: &str, current: &str, historical: Vec<Currency>) -> Country {
+        Country {
+            iso_3166_1_a2: a2.into(),
+            currency: Some(currency(current, None)),
+            historical_currencies: Some(historical),
+            ..Country::default()
+        }
+    }
+
+    fn codes(v: &Option<Vec<Currency>>) -> Vec<(&str, Option<&str>)> {
+        v.iter().flatten()
+            .map(|c|(c.iso_4217.as_deref().unwrap_or_default(), c.withdrawn.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn tidies_historical_currencies_latest_first() {
+        let mut c = country("HR", "EUR", vec![
+            currency("YUN", Some("1990")),
+            currency("HRK", Some("2023-01")),
+            currency("EUR", None),
+            currency("HRD", Some("1995-01")),
+            currency("HRK", Some("2023-01")),
+        ]);
+
+        c.tidy_currencies();
+
+        assert_eq!(codes(&c.historical_currencies), vec![
+            ("HRK", Some("2023-01")), ("HRD", Some("1995-01")), ("YUN", Some("1990"))
+        ]);
+
+        let mut c = country("FI", "EUR", vec![currency("EUR", None)]);
+        c.tidy_currencies();
+
+        assert!(c.historical_currencies.is_none());
+    }
+
+    #[test]
+    fn replaces_withdrawn_currency() {
+        let dollar = Currency { symbol: Some("$".into()), ..currency("USD", None) };
+        let in_use = BTreeMap::from([("USD".to_string(), dollar)]);
+        let mut c = country("US", "USS", vec![]);
+
+        assert_eq!(c.retire_unlisted_currency(&in_use), Some(("USS".into(), "USD".into())));
+        assert_eq!(c.currency.as_ref().unwrap().symbol.as_deref(), Some("$"));
+        assert_eq!(codes(&c.historical_currencies), vec![("USS", Some("2014-03"))]);
+
+        // Replacement not in use anywhere comes from the ISO 4217 list
+        let mut c = country("SD", "SDD", vec![currency("SDD", None)]);
+
+        assert!(c.retire_unlisted_currency(&BTreeMap::new()).is_some());
+
+        let sdg = c.currency.unwrap();
+
+        assert_eq!((sdg.name.as_str(), sdg.numeric_code, sdg.minor_unit),
+            ("Sudanese Pound", Some(938), Some(2)));
+        assert_eq!(codes(&c.historical_currencies), vec![("SDD", Some("2007-07"))]);
+    }
+
+    #[test]
+    fn keeps_currencies_without_known_replacement() {
+        let mut c = country("FI", "EUR", vec![]);
+
+        assert_eq!(c.retire_unlisted_currency(&BTreeMap::new()), None);
+
+        // RUR was replaced by other currencies elsewhere
+        let mut c = country("XX", "RUR", vec![]);
+
+        assert_eq!(c.retire_unlisted_currency(&BTreeMap::new()), None);
+        assert_eq!(c.currency.unwrap().iso_4217.as_deref(), Some("RUR"));
+    }
+
+    #[test]
+    fn fills_values_of_the_list() {
+        let mut c = currency("JPY", None);
+        c.resolve_listed();
+
+        assert_eq!((c.numeric_code, c.minor_unit), (Some(392), Some(0)));
+
+        let mut c = Currency { minor_unit: Some(3), ..currency("JPY", None) };
+        c.resolve_listed();
+
+        assert_eq!(c.minor_unit, Some(3));
+        assert!(Currency::listed("USS").is_none());
+        assert_eq!(currencies_in_use(&BTreeMap::from([(1, country("US", "USD", vec![]))])).len(), 1);
+    }
+}
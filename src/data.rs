@@ -0,0 +1,213 @@
+//! Countries of the last build embedded into the crate. Lookups need no network
+//! nor files and hand out references that live as long as the program does.
+//!
+//! ```
+//! use bubbles_lib::CountryCode;
+//!
+//! let fi = bubbles_lib::data::by_alpha2("fi").unwrap();
+//! let eur = bubbles_lib::data::by_currency("EUR");
+//! let se = CountryCode::SE.country();
+//!
+//! assert!(eur.iter().any(|c|c.iso_3166_1_a2 == fi.iso_3166_1_a2));
+//! assert_eq!(se.unwrap().exonym, "Sweden");
+//! ```
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use crate::{backfill_alpha2, Alpha3, CallingCode, Country, CountryCode, CountryCodeA3, Currency, CurrencyCode, Tld};
+
+const DATASET: &str = include_str!("../out.json");
+
+static COUNTRIES: OnceLock<BTreeMap<CountryCode, Country>> = OnceLock::new();
+
+/// Every country of the dataset by their alpha-2 codes
+pub fn countries() -> &'static BTreeMap<CountryCode, Country> {
+    COUNTRIES.get_or_init(||{
+        let mut countries = serde_json::from_str::<BTreeMap<String, Country>>(DATASET)
+            .expect("Embedded dataset is not valid countries JSON");
+
+        backfill_alpha2(&mut countries);
+
+        // Countries the code enums don't know (yet) can't be looked up
+        countries.into_iter()
+            .filter_map(|(k, c)|k.parse::<CountryCode>().ok().map(|k|(k, c)))
+            .collect()
+    })
+}
+
+pub fn by_alpha2(code: &str) -> Option<&'static Country> {
+    countries().get(&code.parse().ok()?)
+}
+
+pub fn by_alpha3(code: &str) -> Option<&'static Country> {
+    let code = Alpha3::new(code).ok()?;
+
+    countries().values().find(|c|c.iso_3166_1_a3.as_ref() == Some(&code))
+}
+
+pub fn by_numeric(code: u16) -> Option<&'static Country> {
+    countries().values().find(|c|c.iso_3166_1_numeric == Some(code))
+}
+
+/// Country by any of its names, case doesn't matter. Exonyms are tried first.
+pub fn by_name(name: &str) -> Option<&'static Country> {
+    let name = name.to_lowercase();
+
+    countries().values()
+        .find(|c|c.exonym.to_lowercase().eq(&name))
+        .or_else(||countries().values().find(|c|c.names().iter().any(|s|s.to_lowercase().eq(&name))))
+}
+
+/// Country by its top level domain, with or without the dot
+pub fn by_tld(tld: &str) -> Option<&'static Country> {
+    let tld = Tld::new(tld).ok()?;
+
+    countries().values().find(|c|c.tld.as_ref() == Some(&tld))
+}
+
+/// Countries sharing the calling code, `+1` or `1`. Area codes are ignored.
+pub fn by_calling_code(code: &str) -> Vec<&'static Country> {
+    let code = match CallingCode::new(code) {
+        Ok(c) => c,
+        Err(_) => return vec![],
+    };
+
+    countries().values()
+        .filter(|c|c.calling_code.as_ref().is_some_and(|s|s.code() == code.code()))
+        .collect()
+}
+
+/// Countries using the currency with the ISO 4217 code
+pub fn by_currency(code: &str) -> Vec<&'static Country> {
+    countries().values()
+        .filter(|c|c.currency.as_ref()
+            .and_then(|x|x.iso_4217.as_ref())
+            .is_some_and(|s|s.eq_ignore_ascii_case(code)))
+        .collect()
+}
+
+/// Countries speaking the language, given as ISO 639 code or english name
+pub fn by_language(language: &str) -> Vec<&'static Country> {
+    countries().values()
+        .filter(|c|c.languages.iter().flatten().any(|l|
+            l.name.eq_ignore_ascii_case(language)
+            || l.iso_639_1.as_ref().is_some_and(|s|s.eq_ignore_ascii_case(language))
+            || l.iso_639_3.as_ref().is_some_and(|s|s.eq_ignore_ascii_case(language))
+        ))
+        .collect()
+}
+
+impl CountryCode {
+    pub fn country(&self) -> Option<&'static Country> {
+        countries().get(self)
+    }
+}
+
+impl CountryCodeA3 {
+    pub fn country(&self) -> Option<&'static Country> {
+        countries().get(&self.alpha2())
+    }
+}
+
+impl CurrencyCode {
+    /// The currency as the countries using it have it
+    pub fn currency(&self) -> Option<&'static Currency> {
+        let code = Some(self.as_str());
+
+        countries().values()
+            .filter_map(|c|c.currency.as_ref())
+            .find(|c|c.iso_4217.as_deref() == code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(countries: Vec<&Country>) -> Vec<&str> {
+        countries.iter().map(|c|c.iso_3166_1_a2.as_str()).collect()
+    }
+
+    #[test]
+    fn looks_up_by_codes() {
+        assert_eq!(by_alpha2("fi").unwrap().exonym, "Finland");
+        assert_eq!(by_alpha3("FIN").unwrap().iso_3166_1_a2, "FI");
+        assert_eq!(by_alpha3("swe").unwrap().iso_3166_1_a2, "SE");
+        assert_eq!(by_numeric(246).unwrap().iso_3166_1_a2, "FI");
+        assert!(by_alpha2("XX").is_none());
+        assert!(by_alpha3("FINN").is_none());
+        assert!(by_numeric(0).is_none());
+    }
+
+    #[test]
+    fn looks_up_by_names() {
+        assert_eq!(by_name("finland").unwrap().iso_3166_1_a2, "FI");
+        assert_eq!(by_name("Suomi").unwrap().iso_3166_1_a2, "FI");
+        assert_eq!(by_name("Россия").unwrap().iso_3166_1_a2, "RU");
+        assert!(by_name("Atlantis").is_none());
+    }
+
+    #[test]
+    fn looks_up_by_tld_and_calling_code() {
+        assert_eq!(by_tld(".fi").unwrap().iso_3166_1_a2, "FI");
+        assert_eq!(by_tld("FI").unwrap().iso_3166_1_a2, "FI");
+        assert!(by_tld("nope.").is_none());
+
+        let nanp = codes(by_calling_code("+1"));
+
+        assert!(nanp.contains(&"US") && nanp.contains(&"CA") && nanp.contains(&"JM"));
+        assert_eq!(codes(by_calling_code("358")), vec!["AX", "FI"]);
+        assert!(by_calling_code("not a code").is_empty());
+    }
+
+    #[test]
+    fn looks_up_by_currency() {
+        let eur = codes(by_currency("eur"));
+
+        assert!(eur.contains(&"FI") && eur.contains(&"HR") && eur.contains(&"NL"));
+        assert_eq!(codes(by_currency("RUB")), vec!["RU"]);
+        assert_eq!(codes(by_currency("USD"))[..], codes(by_currency("usd"))[..]);
+        assert!(codes(by_currency("USD")).contains(&"US"));
+
+        // Withdrawn currencies are not in use anywhere
+        assert!(by_currency("HRK").is_empty());
+        assert!(by_currency("RUR").is_empty());
+    }
+
+    #[test]
+    fn looks_up_by_language() {
+        assert_eq!(codes(by_language("fi")), vec!["AX", "FI"]);
+        assert_eq!(codes(by_language("fin")), codes(by_language("Finnish")));
+
+        let swedish = codes(by_language("swedish"));
+
+        assert!(swedish.contains(&"FI") && swedish.contains(&"SE"));
+        assert!(by_language("Klingon").is_empty());
+    }
+
+    #[test]
+    fn code_enums_find_their_countries() {
+        assert_eq!(CountryCode::FI.country().unwrap().exonym, "Finland");
+        assert_eq!(CountryCodeA3::FIN.country().unwrap().iso_3166_1_a2, "FI");
+        assert_eq!(CurrencyCode::RUB.currency().unwrap().numeric_code, Some(643));
+        assert_eq!(CurrencyCode::EUR.currency().unwrap().minor_unit, Some(2));
+        assert!(CurrencyCode::XDR.currency().is_none());
+    }
+
+    #[test]
+    fn dataset_is_consistent() {
+        assert_eq!(countries().len(), 249);
+
+        for (k, c) in countries() {
+            assert_eq!(k.as_str(), c.iso_3166_1_a2);
+            assert!(c.iso_3166_1_numeric.is_some(), "{} has no numeric code", k);
+
+            // Current currencies are on the ISO 4217 list, withdrawn ones are not current
+            if let Some(code) = c.currency.as_ref().and_then(|x|x.iso_4217.as_ref()) {
+                assert!(code.parse::<CurrencyCode>().is_ok(), "{} of {} is not listed", code, k);
+                assert!(c.historical_currencies.iter().flatten()
+                    .all(|h|h.iso_4217.as_ref() != Some(code)), "{} of {}", code, k);
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+use crate::DataSource;
+use crate::error::{Error, Result};
+#[cfg(feature = "scrape")]
+use crate::scrape::Table;
+
+/// Shape of a source as it was read. Distinct header rows of the tables (or keys
+/// of the JSON records) and the number of rows found from them.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct Fingerprint {
+    pub headers: Vec<Vec<String>>,
+    pub rows: usize,
+}
+
+impl Fingerprint {
+    #[cfg(feature = "scrape")]
+    pub fn of_tables(tables: &[Table]) -> Self {
+        let mut headers = tables.iter()
+            .map(|t|t.headers.values().cloned().collect::<Vec<String>>())
+            .collect::<Vec<_>>();
+
+        headers.sort();
+        headers.dedup();
+
+        Self { headers, rows: tables.iter().map(|t|t.rows.len()).sum() }
+    }
+
+    /// Fingerprint of JSON array of records. Keys of the records stand in for the
+    /// table headers.
+    pub fn of_json(url: &str, json: &str) -> Result<Self> {
+        let records = serde_json::from_str::<Vec<serde_json::Map<String, serde_json::Value>>>(json)
+            .map_err(|e| Error::Json { url: url.to_string(), source: e })?;
+
+        let mut headers = records.iter()
+            .map(|r|r.keys().cloned().collect::<Vec<String>>())
+            .collect::<Vec<_>>();
+
+        headers.sort();
+        headers.dedup();
+
+        Ok(Self { headers, rows: records.len() })
+    }
+
+    /// Ways this fingerprint differs from the one of a previous build. Row count
+    /// is allowed to drop by `max_row_drop` percents.
+    pub fn drift(&self, previous: &Fingerprint, max_row_drop: f64) -> Vec<Drift> {
+        let mut drift = vec![];
+
+        let added = self.headers.iter()
+            .filter(|h|! previous.headers.contains(h))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let removed = previous.headers.iter()
+            .filter(|h|! self.headers.contains(h))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if ! added.is_empty() || ! removed.is_empty() {
+            drift.push(Drift::Headers { added, removed });
+        }
+
+        let min_rows = previous.rows as f64 * (1.0 - max_row_drop / 100.0);
+
+        if (self.rows as f64) < min_rows {
+            drift.push(Drift::Rows { previous: previous.rows, current: self.rows });
+        }
+
+        drift
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Drift {
+    Headers { added: Vec<Vec<String>>, removed: Vec<Vec<String>> },
+    Rows { previous: usize, current: usize },
+}
+
+impl Display for Drift {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Headers { added, removed } => write!(f, "headers changed, \
+                added {:?} and removed {:?}", added, removed),
+            Self::Rows { previous, current } => write!(f, "row count dropped \
+                from {} to {}", previous, current),
+        }
+    }
+}
+
+/// What to do when a source has drifted from the previous build
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum DriftAction {
+    #[default]
+    Warn,
+    Fail,
+}
+
+impl FromStr for DriftAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "warn" => Ok(Self::Warn),
+            "fail" => Ok(Self::Fail),
+            x => Err(format!("Unknown drift action {}, expected warn or fail", x)),
+        }
+    }
+}
+
+impl Display for DriftAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Warn => write!(f, "warn"),
+            Self::Fail => write!(f, "fail"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DriftConfig {
+    pub action: DriftAction,
+    /// Allowed drop of row count in percents
+    pub max_row_drop: f64,
+}
+
+impl Default for DriftConfig {
+    fn default() -> Self {
+        Self { action: DriftAction::Warn, max_row_drop: 10.0 }
+    }
+}
+
+/// Collects fingerprints of the sources read during a build and compares them
+/// to the ones recorded by the previous build.
+#[derive(Debug, Default)]
+pub struct DriftGuard {
+    config: DriftConfig,
+    previous: BTreeMap<DataSource, Fingerprint>,
+    pub current: BTreeMap<DataSource, Fingerprint>,
+    pub drifted: Vec<(DataSource, Drift)>,
+}
+
+impl DriftGuard {
+    pub fn new(config: DriftConfig, previous: BTreeMap<DataSource, Fingerprint>) -> Self {
+        Self { config, previous, current: BTreeMap::new(), drifted: vec![] }
+    }
+
+    /// Record fingerprint of the source. Drift is only printed out when warning,
+    /// with failing config the source is reported back as failed.
+    pub fn check(&mut self, source: DataSource, fingerprint: Fingerprint) -> Result<()> {
+        let drift = match self.previous.get(&source) {
+            Some(p) => fingerprint.drift(p, self.config.max_row_drop),
+            None => vec![],
+        };
+
+        self.current.insert(source, fingerprint);
+
+        if drift.is_empty() {
+            return Ok(())
+        }
+
+        for d in drift.iter() {
+            eprintln!("Source {} has drifted from the previous build: {}", source, d);
+        }
+
+        let messages = drift.iter().map(|d|d.to_string()).collect();
+        self.drifted.extend(drift.into_iter().map(|d|(source, d)));
+
+        match self.config.action {
+            DriftAction::Warn => Ok(()),
+            DriftAction::Fail => Err(Error::SchemaDrift { name: source.to_string(), drift: messages }),
+        }
+    }
+
+    /// Fails if any of the sources drifted and config says drift is not ok
+    pub fn finish(&self) -> Result<()> {
+        if self.config.action == DriftAction::Warn || self.drifted.is_empty() {
+            return Ok(())
+        }
+
+        let mut names = self.drifted.iter()
+            .map(|(s, _)|s.to_string())
+            .collect::<Vec<_>>();
+
+        names.dedup();
+
+        Err(Error::SchemaDrift {
+            name: names.join(", "),
+            drift: self.drifted.iter().map(|(_, d)|d.to_string()).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use DataSource::*;
+
+    fn fingerprint(headers: &[&[&str]], rows: usize) -> Fingerprint {
+        Fingerprint {
+            headers: headers.iter().map(|h|h.iter().map(|s|s.to_string()).collect()).collect(),
+            rows,
+        }
+    }
+
+    fn guard(action: DriftAction) -> DriftGuard {
+        let previous = [(DatahubCountries, fingerprint(&[&["Code", "Name"]], 100))];
+
+        DriftGuard::new(DriftConfig { action, max_row_drop: 10.0 }, previous.into_iter().collect())
+    }
+
+    #[test]
+    fn same_shape_has_no_drift() {
+        let previous = fingerprint(&[&["Code", "Name"]], 100);
+
+        assert!(previous.drift(&previous, 10.0).is_empty());
+        assert!(fingerprint(&[&["Code", "Name"]], 120).drift(&previous, 10.0).is_empty());
+    }
+
+    #[test]
+    fn added_and_removed_headers_drift() {
+        let previous = fingerprint(&[&["Code", "Name"], &["Currency"]], 100);
+        let current = fingerprint(&[&["Code", "Name", "Capital"], &["Currency"]], 100);
+
+        assert_eq!(current.drift(&previous, 10.0), vec![Drift::Headers {
+            added: vec![vec!["Code".into(), "Name".into(), "Capital".into()]],
+            removed: vec![vec!["Code".into(), "Name".into()]],
+        }]);
+
+        let current = fingerprint(&[&["Code", "Name"]], 100);
+
+        assert_eq!(current.drift(&previous, 10.0), vec![Drift::Headers {
+            added: vec![], removed: vec![vec!["Currency".into()]],
+        }]);
+    }
+
+    #[test]
+    fn rows_may_drop_up_to_the_threshold() {
+        let previous = fingerprint(&[&["Code"]], 100);
+
+        assert!(fingerprint(&[&["Code"]], 90).drift(&previous, 10.0).is_empty());
+        assert_eq!(fingerprint(&[&["Code"]], 89).drift(&previous, 10.0),
+            vec![Drift::Rows { previous: 100, current: 89 }]);
+        assert!(fingerprint(&[&["Code"]], 0).drift(&previous, 100.0).is_empty());
+    }
+
+    #[test]
+    fn fingerprints_json_records() {
+        let json = r#"[{"Code": "FI", "Name": "Finland"}, {"Code": "SE", "Name": "Sweden"}, {"Code": "XK"}]"#;
+        let f = Fingerprint::of_json("test", json).unwrap();
+
+        assert_eq!(f, fingerprint(&[&["Code"], &["Code", "Name"]], 3));
+        assert!(Fingerprint::of_json("test", "{}").is_err());
+    }
+
+    #[test]
+    fn warning_guard_records_drift_and_finishes() {
+        let mut guard = guard(DriftAction::Warn);
+
+        assert!(guard.check(DatahubCountries, fingerprint(&[&["Code"]], 50)).is_ok());
+        assert!(guard.check(WikipediaCountries, fingerprint(&[&["Code"]], 1)).is_ok());
+        assert_eq!(guard.drifted.len(), 2);
+        assert_eq!(guard.current.len(), 2);
+        assert!(guard.finish().is_ok());
+    }
+
+    #[test]
+    fn failing_guard_fails_check_and_finish() {
+        let mut guard = guard(DriftAction::Fail);
+
+        // Sources without a previous fingerprint can't drift
+        assert!(guard.check(WikipediaCountries, fingerprint(&[&["Code"]], 1)).is_ok());
+        assert!(guard.finish().is_ok());
+
+        let e = guard.check(DatahubCountries, fingerprint(&[&["Code", "Name"]], 50)).unwrap_err();
+
+        assert!(matches!(e, Error::SchemaDrift { ref drift, .. } if drift.len() == 1));
+        assert!(guard.current.contains_key(&DatahubCountries));
+        assert!(matches!(guard.finish(), Err(Error::SchemaDrift { ref name, .. })
+            if name == &DatahubCountries.to_string()));
+    }
+}
@@ -0,0 +1,65 @@
+use std::path::PathBuf;
+use thiserror::Error;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything that can go wrong while reading the sources. Variants carry the url
+/// (or file path) of the source and where on it the problem was found.
+#[derive(Error, Debug)]
+pub enum Error {
+    #[cfg(any(feature = "scrape", feature = "pull"))]
+    #[error("Failed to read http document {url}: {source}")]
+    Network { url: String, source: reqwest::Error },
+
+    #[error("Http document {url} responded with status {status}")]
+    HttpStatus { url: String, status: u16 },
+
+    #[error("Failed to read file {path}: {source}")]
+    File { path: PathBuf, source: std::io::Error },
+
+    #[error("Failed to find {what} table from {url}")]
+    TableNotFound { url: String, what: String },
+
+    #[error("Found {found} tables that could be the {what} table from {url}")]
+    AmbiguousTables { url: String, what: String, found: usize },
+
+    #[error("Column {column} on row {row} of table {table} from {url} did not have a value")]
+    MissingValue { url: String, table: usize, row: usize, column: String },
+
+    #[error("Failed to read row {row} of table {table} from {url}: {message}")]
+    InvalidRow { url: String, table: usize, row: usize, message: String },
+
+    #[error("Failed to read JSON from {url}: {source}")]
+    Json { url: String, source: serde_json::Error },
+
+    #[error("Source {name} has drifted from the previous build: {}", .drift.join(", "))]
+    SchemaDrift { name: String, drift: Vec<String> },
+
+    #[error("Invalid table selector '{selector}': {message}")]
+    InvalidSelector { selector: String, message: String },
+
+    #[error("Failed to read amount '{input}': {message}")]
+    InvalidAmount { input: String, message: String },
+
+    #[error("Minor unit of currency {currency} is not known")]
+    UnknownMinorUnit { currency: String },
+
+    #[error("Failed to write countries as {format}: {message}")]
+    Output { format: String, message: String },
+
+    #[error("Failed to read {format} from {url}: {message}")]
+    Input { url: String, format: String, message: String },
+
+    #[cfg(feature = "sqlite")]
+    #[error("Failed to write SQLite database {path}: {source}")]
+    Sqlite { path: String, source: rusqlite::Error },
+
+    #[error("Invalid {kind} '{value}': {message}")]
+    InvalidValue { kind: String, value: String, message: String },
+
+    #[error("Unknown {kind} code '{code}'")]
+    UnknownCode { kind: String, code: String },
+
+    #[error("Failed to export table {table} as {format}: {message}")]
+    Export { table: usize, format: String, message: String },
+}
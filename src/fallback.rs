@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+
+use crate::{Country, Currency, DataSource};
+use crate::merge::Merger;
+
+impl DataSource {
+    /// Country fields the source provides values for, nested ones as `currency.symbol`
+    pub fn fields(&self) -> &'static [&'static str] {
+        match self {
+            Self::WikipediaCountries => &["exonym", "name", "aliases", "iso_3166_1_a2",
+                "iso_3166_1_a3", "iso_3166_1_numeric", "tld"],
+            Self::DatahubCountries => &["exonym", "name", "aliases", "iso_3166_1_a2"],
+            Self::WikipediaEndonyms => &["aliases", "capital", "capital_endonyms", "endonyms",
+                "languages", "scripts"],
+            Self::DatahubCurrencies => &["currency.name", "currency.iso_4217",
+                "currency.numeric_code", "currency.minor_unit", "historical_currencies"],
+            Self::WikipediaCurrencies => &["currency.name", "currency.iso_4217", "currency.symbol",
+                "currency.symbols", "currency.fraction", "currency.fractions_in_unit"],
+            Self::WikipediaFlagEmojis => &["flag"],
+            Self::WikipediaCallingCodes => &["time", "calling_code"],
+            Self::WikipediaOfficialLanguages => &["languages"],
+        }
+    }
+
+    /// Source lists the countries instead of just adding details to them
+    pub fn lists_countries(&self) -> bool {
+        matches!(self, Self::WikipediaCountries | Self::DatahubCountries)
+    }
+}
+
+/// Add the values a failed source gave in the previous build as if the source had
+/// given them now. Countries not known by the other sources are brought back only
+/// if the source is one listing them. Returns the number of countries restored.
+pub fn restore(merger: &mut Merger, previous: &BTreeMap<String, Country>, source: DataSource)
+-> usize {
+    let mut restored = 0;
+
+    for (k, p) in previous.iter() {
+        if ! merger.contains(k) && ! source.lists_countries() {
+            continue;
+        }
+
+        let mut c = Country::default();
+
+        if c.restore(p, source.fields()) {
+            merger.add(k, source, c);
+            restored += 1;
+        }
+    }
+
+    restored
+}
+
+impl Country {
+    /// Take the given fields from the previous version of the country where this
+    /// one has no value. Returns true if anything was taken.
+    pub fn restore(&mut self, previous: &Country, fields: &[&str]) -> bool {
+        let mut restored = false;
+
+        for f in fields {
+            let taken = match *f {
+                "exonym" => restore_string(&mut self.exonym, &previous.exonym),
+                "name" => restore_string(&mut self.name, &previous.name),
+                "aliases" => restore_option(&mut self.aliases, &previous.aliases),
+                "capital" => restore_option(&mut self.capital, &previous.capital),
+                "capital_endonyms" => restore_option(&mut self.capital_endonyms, &previous.capital_endonyms),
+                "iso_3166_1_a2" => restore_string(&mut self.iso_3166_1_a2, &previous.iso_3166_1_a2),
+                "iso_3166_1_a3" => restore_option(&mut self.iso_3166_1_a3, &previous.iso_3166_1_a3),
+                "iso_3166_1_numeric" => restore_option(&mut self.iso_3166_1_numeric,
+                    &previous.iso_3166_1_numeric),
+                "tld" => restore_option(&mut self.tld, &previous.tld),
+                "historical_currencies" => restore_option(&mut self.historical_currencies,
+                    &previous.historical_currencies),
+                "flag" => restore_option(&mut self.flag, &previous.flag),
+                "time" => restore_option(&mut self.time, &previous.time),
+                "calling_code" => restore_option(&mut self.calling_code, &previous.calling_code),
+                "endonyms" => restore_option(&mut self.endonyms, &previous.endonyms),
+                "languages" => restore_option(&mut self.languages, &previous.languages),
+                "scripts" => restore_option(&mut self.scripts, &previous.scripts),
+                s => match s.strip_prefix("currency.") {
+                    Some(n) => self.restore_currency(previous, n),
+                    None => false,
+                },
+            };
+
+            restored = restored || taken;
+        }
+
+        restored
+    }
+
+    fn restore_currency(&mut self, previous: &Country, field: &str) -> bool {
+        let p = match previous.currency {
+            Some(ref c) => c,
+            None => return false,
+        };
+
+        let had = self.currency.is_some();
+        let c = self.currency.get_or_insert_with(Currency::default);
+
+        let taken = match field {
+            "name" => restore_string(&mut c.name, &p.name),
+            "iso_4217" => restore_option(&mut c.iso_4217, &p.iso_4217),
+            "numeric_code" => restore_option(&mut c.numeric_code, &p.numeric_code),
+            "minor_unit" => restore_option(&mut c.minor_unit, &p.minor_unit),
+            "symbol" => restore_option(&mut c.symbol, &p.symbol),
+            "symbols" => restore_option(&mut c.symbols, &p.symbols),
+            "fraction" => restore_option(&mut c.fraction, &p.fraction),
+            "fractions_in_unit" => restore_option(&mut c.fractions_in_unit, &p.fractions_in_unit),
+            _ => false,
+        };
+
+        // Nothing taken into a currency of our own, leave it out
+        if ! had && ! taken {
+            self.currency = None;
+        }
+
+        taken
+    }
+}
+
+fn restore_string(current: &mut String, previous: &str) -> bool {
+    match current.is_empty() && ! previous.is_empty() {
+        true => {
+            *current = previous.to_string();
+            true
+        },
+        false => false,
+    }
+}
+
+fn restore_option<T: Clone>(current: &mut Option<T>, previous: &Option<T>) -> bool {
+    match current.is_none() && previous.is_some() {
+        true => {
+            *current = previous.to_owned();
+            true
+        },
+        false => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merge::MergePolicy;
+    use crate::Tld;
+    use DataSource::*;
+
+    fn previous() -> BTreeMap<String, Country> {
+        let fi = Country {
+            exonym: "Finland".into(), iso_3166_1_a2: "FI".into(),
+            tld: Some(Tld::new(".fi").unwrap()),
+            currency: Some(Currency {
+                name: "Euro".into(), iso_4217: Some("EUR".into()), symbol: Some("€".into()),
+                ..Currency::default()
+            }),
+            ..Country::default()
+        };
+        let se = Country { exonym: "Sweden".into(), iso_3166_1_a2: "SE".into(), ..Country::default() };
+
+        [("FI".to_string(), fi), ("SE".to_string(), se)].into_iter().collect()
+    }
+
+    #[test]
+    fn restores_only_missing_fields() {
+        let previous = &previous()["FI"];
+        let mut c = Country { exonym: "Suomi".into(), ..Country::default() };
+
+        assert!(c.restore(previous, &["exonym", "tld", "currency.symbol", "unknown"]));
+        assert_eq!(c.exonym, "Suomi");
+        assert_eq!(c.tld, previous.tld);
+
+        let currency = c.currency.as_ref().unwrap();
+
+        assert_eq!(currency.symbol.as_deref(), Some("€"));
+        assert_eq!(currency.name, "");
+
+        // Everything asked for is there already
+        assert!(! c.restore(previous, &["exonym", "tld", "currency.symbol"]));
+    }
+
+    #[test]
+    fn restores_nothing_the_previous_build_lacks() {
+        let previous = &previous()["SE"];
+        let mut c = Country::default();
+
+        assert!(! c.restore(previous, &["tld", "flag", "currency.name", "currency.symbol"]));
+        assert!(c.tld.is_none());
+        assert!(c.currency.is_none());
+
+        // Currency without the asked values is not brought back empty
+        let mut c = Country::default();
+
+        assert!(! c.restore(&self::previous()["FI"], &["currency.fraction"]));
+        assert!(c.currency.is_none());
+    }
+
+    #[test]
+    fn only_listing_sources_bring_back_countries() {
+        assert!(WikipediaCountries.lists_countries());
+        assert!(DatahubCountries.lists_countries());
+        assert!(! DatahubCurrencies.lists_countries());
+        assert!(! WikipediaFlagEmojis.lists_countries());
+
+        let mut merger = Merger::default();
+        merger.add("FI", WikipediaCountries, Country { exonym: "Finland".into(), ..Country::default() });
+
+        // SE is not known to the merger and currencies don't list countries
+        assert_eq!(restore(&mut merger, &previous(), DatahubCurrencies), 1);
+        assert!(! merger.contains("SE"));
+
+        assert_eq!(restore(&mut merger, &previous(), DatahubCountries), 2);
+        assert!(merger.contains("SE"));
+
+        let countries = merger.countries(&MergePolicy::recommended());
+
+        assert_eq!(countries["FI"].currency.as_ref().unwrap().iso_4217.as_deref(), Some("EUR"));
+        assert_eq!(countries["SE"].exonym, "Sweden");
+    }
+
+    #[test]
+    fn sources_without_previous_values_restore_nothing() {
+        let mut merger = Merger::default();
+        merger.add("FI", WikipediaCountries, Country::default());
+
+        assert_eq!(restore(&mut merger, &previous(), WikipediaFlagEmojis), 0);
+        assert_eq!(restore(&mut merger, &BTreeMap::new(), WikipediaCountries), 0);
+    }
+}
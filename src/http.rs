@@ -0,0 +1,18 @@
+use crate::error::{Error, Result};
+
+/// Read the response body of a GET request as text
+pub(crate) fn http_get(url: &str) -> Result<String> {
+    let resp = match reqwest::blocking::get(url) {
+        Ok(r) => r,
+        Err(e) => return Err(Error::Network { url: url.to_string(), source: e }),
+    };
+
+    if ! resp.status().is_success() {
+        return Err(Error::HttpStatus { url: url.to_string(), status: resp.status().as_u16() })
+    }
+
+    match resp.text() {
+        Ok(s) => Ok(s),
+        Err(e) => Err(Error::Network { url: url.to_string(), source: e }),
+    }
+}
@@ -0,0 +1,320 @@
+use std::fs::read_to_string;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+
+use crate::{Country, Language, LanguageStatus, Script};
+use crate::error::{Error, Result};
+
+const BUNDLED: &str = include_str!("../data/languages.json");
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct LanguageEntry {
+    pub name: String,
+    pub iso_639_1: Option<String>,
+    pub iso_639_3: String,
+    /// ISO 15924 scripts the language is usually written in, most common first
+    pub scripts: Vec<String>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ScriptEntry {
+    /// ISO 15924 code
+    pub code: String,
+    pub name: String,
+    /// Written right to left
+    pub rtl: bool,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// Languages and scripts we know the codes of. Sources name languages in english,
+/// the registry tells which language (or script, sources mix them) the name means.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct LanguageRegistry {
+    pub languages: Vec<LanguageEntry>,
+    pub scripts: Vec<ScriptEntry>,
+}
+
+impl LanguageRegistry {
+    /// Registry shipped with the crate
+    pub fn bundled() -> Self {
+        serde_json::from_str(BUNDLED).expect("Bundled language registry is not valid JSON")
+    }
+
+    /// Read registry of the same shape as `data/languages.json` from a file
+    pub fn read(path: &Path) -> Result<Self> {
+        let json = read_to_string(path)
+            .map_err(|e| Error::File { path: path.to_owned(), source: e })?;
+
+        serde_json::from_str(&json)
+            .map_err(|e| Error::Json { url: path.display().to_string(), source: e })
+    }
+
+    /// Language by its english name, alias or ISO 639 code
+    pub fn language(&self, name: &str) -> Option<&LanguageEntry> {
+        keys(name).into_iter().find_map(|k|self.languages.iter().find(|l|
+            k.eq(&l.iso_639_3) || l.iso_639_1.as_ref().is_some_and(|c|c.eq(&k))
+            || [&l.name].into_iter().chain(l.aliases.iter()).any(|s|s.to_lowercase().eq(&k))
+        ))
+    }
+
+    /// Script by its english name, alias or ISO 15924 code
+    pub fn script(&self, name: &str) -> Option<&ScriptEntry> {
+        keys(name).into_iter().find_map(|k|self.scripts.iter().find(|s|
+            s.code.to_lowercase().eq(&k)
+            || [&s.name].into_iter().chain(s.aliases.iter()).any(|x|x.to_lowercase().eq(&k))
+        ))
+    }
+}
+
+impl Default for LanguageRegistry {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+/// Name as is and without the notes in brackets, `Galician (R)` is also `Galician`.
+/// Exact name is tried first.
+fn keys(name: &str) -> Vec<String> {
+    let mut stripped = String::new();
+    let mut depth = 0;
+
+    for c in name.chars() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            c if depth == 0 => stripped.push(c),
+            _ => (),
+        }
+    }
+
+    let mut keys = vec![name.trim().to_lowercase()];
+    let stripped = stripped.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+
+    if ! keys.contains(&stripped) {
+        keys.push(stripped);
+    }
+
+    keys
+}
+
+impl Country {
+    /// Fill in ISO 639 codes of the languages and the scripts used in the country.
+    /// Script names the sources list as languages are moved to the scripts.
+    pub fn resolve_languages(&mut self, registry: &LanguageRegistry) {
+        let mut languages = vec![];
+        // Scripts taken from the previous build stay, their language names are
+        // already stripped of them
+        let mut scripts = self.scripts.take().unwrap_or_default().into_iter()
+            .map(|s|s.code)
+            .collect::<Vec<_>>();
+
+        for l in self.languages.take().unwrap_or_default() {
+            if let Some(e) = registry.language(&l.name) {
+                scripts.extend(e.scripts.to_owned());
+                languages.push(Language {
+                    iso_639_1: e.iso_639_1.to_owned(), iso_639_3: Some(e.iso_639_3.to_owned()), ..l
+                });
+
+                continue;
+            }
+
+            match registry.script(&l.name) {
+                Some(s) => scripts.push(s.code.to_owned()),
+                None => {
+                    eprintln!("Unknown language {} of {}", l.name, self.exonym);
+                    languages.push(Language { iso_639_1: None, iso_639_3: None, ..l });
+                },
+            }
+        }
+
+        // Sources name the same language differently and only some of them tell
+        // its status. Keep one per language where it was first listed, the most
+        // prominent status wins.
+        let mut kept: Vec<Language> = vec![];
+
+        for l in languages {
+            match kept.iter_mut().find(|k|language_key(k).eq(&language_key(&l))) {
+                Some(k) if status_rank(&l) < status_rank(k) => *k = l,
+                Some(_) => (),
+                None => kept.push(l),
+            }
+        }
+
+        let mut languages = kept;
+
+        // Within the same status languages the country has a name in come first,
+        // otherwise the order of the sources tells how prominent they are
+        let named = self.endonyms.iter().flatten()
+            .filter_map(|e|e.language())
+            .filter_map(|l|registry.language(l))
+            .map(|e|e.iso_639_3.to_owned())
+            .collect::<Vec<_>>();
+        let unnamed = |l: &Language|! l.iso_639_3.as_ref().is_some_and(|c|named.contains(c));
+
+        languages.sort_by(|a, b|status_rank(a).cmp(&status_rank(b))
+            .then(unnamed(a).cmp(&unnamed(b))));
+
+        // Endonyms written in scripts the languages don't tell about
+        for e in self.endonyms.iter().flatten().filter(|e|! e.romanized) {
+            if let Some(ref s) = e.script {
+                scripts.push(s.to_owned());
+            }
+        }
+
+        scripts.sort();
+        scripts.dedup();
+
+        self.scripts = match scripts.is_empty() {
+            true => None,
+            false => Some(scripts.into_iter()
+                .map(|c|match registry.script(&c) {
+                    Some(s) => Script { code: s.code.to_owned(), name: s.name.to_owned(), rtl: s.rtl },
+                    None => Script { name: c.to_owned(), code: c, rtl: false },
+                })
+                .collect()),
+        };
+
+        self.languages = match languages.is_empty() {
+            true => None,
+            false => Some(languages),
+        };
+
+        let locales = self.generate_locales();
+
+        self.locales = match locales.is_empty() {
+            true => None,
+            false => Some(locales),
+        };
+    }
+
+    /// BCP 47 locales from the languages and the alpha-2 code of the country,
+    /// `fi-FI` and `sv-FI` for Finland. Languages without ISO 639 codes are left out.
+    pub fn generate_locales(&self) -> Vec<String> {
+        let mut locales = vec![];
+
+        for l in self.languages.iter().flatten() {
+            let code = match l.iso_639_1.as_ref().or(l.iso_639_3.as_ref()) {
+                Some(c) => c,
+                None => continue,
+            };
+
+            let locale = format!("{}-{}", code, self.iso_3166_1_a2.to_uppercase());
+
+            if ! locales.contains(&locale) {
+                locales.push(locale);
+            }
+        }
+
+        locales
+    }
+
+    /// Locale of the most prominent language of the country
+    pub fn default_locale(&self) -> Option<&str> {
+        self.locales.as_ref()
+            .and_then(|v|v.first())
+            .map(|s|s.as_str())
+    }
+}
+
+fn language_key(l: &Language) -> String {
+    match l.iso_639_3 {
+        Some(ref c) => c.to_owned(),
+        None => l.name.to_lowercase(),
+    }
+}
+
+/// Languages without a status rank after the ones with one
+fn status_rank(l: &Language) -> (bool, Option<LanguageStatus>) {
+    (l.status.is_none(), l.status)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Endonym;
+    use LanguageStatus::*;
+
+    fn language(name: &str, status: Option<LanguageStatus>) -> Language {
+        Language { status, ..Language::new(name) }
+    }
+
+    fn switzerland() -> Country {
+        Country {
+            iso_3166_1_a2: "CH".into(),
+            languages: Some(vec![
+                language("German", Some(CoOfficial)),
+                language("French", Some(CoOfficial)),
+                language("Italian", Some(CoOfficial)),
+                language("Romansh", Some(CoOfficial)),
+                language("French", None),
+            ]),
+            ..Country::default()
+        }
+    }
+
+    #[test]
+    fn languages_keep_source_order_within_status() {
+        let mut country = switzerland();
+        country.resolve_languages(&LanguageRegistry::bundled());
+
+        assert_eq!(country.locales, Some(vec![
+            "de-CH".into(), "fr-CH".into(), "it-CH".into(), "rm-CH".into()
+        ]));
+        assert_eq!(country.default_locale(), Some("de-CH"));
+    }
+
+    #[test]
+    fn most_prominent_status_comes_first() {
+        let mut country = Country {
+            iso_3166_1_a2: "gb".into(),
+            languages: Some(vec![
+                language("Cornish", Some(Recognized)),
+                language("Welsh", Some(Recognized)),
+                language("English", None),
+                language("English", Some(Official)),
+            ]),
+            ..Country::default()
+        };
+        country.resolve_languages(&LanguageRegistry::bundled());
+
+        assert_eq!(country.default_locale(), Some("en-GB"));
+        assert_eq!(country.locales.unwrap()[1..], ["kw-GB", "cy-GB"]);
+    }
+
+    #[test]
+    fn named_languages_come_first_within_status() {
+        let mut country = Country {
+            iso_3166_1_a2: "GB".into(),
+            endonyms: Some(vec![Endonym::new("Cymru", Some("cy"))]),
+            languages: Some(vec![
+                language("English", Some(CoOfficial)),
+                language("Welsh", Some(CoOfficial)),
+            ]),
+            ..Country::default()
+        };
+        country.resolve_languages(&LanguageRegistry::bundled());
+
+        assert_eq!(country.default_locale(), Some("cy-GB"));
+    }
+
+    #[test]
+    fn locales_need_codes() {
+        let mut country = switzerland();
+
+        // Languages not resolved yet have no codes
+        assert!(country.generate_locales().is_empty());
+
+        country.languages = Some(vec![
+            Language { iso_639_3: Some("gsw".into()), ..language("Swiss German", None) },
+            Language { iso_639_1: Some("de".into()), iso_639_3: Some("deu".into()),
+                ..language("German", None) },
+            language("Unknown", None),
+        ]);
+
+        assert_eq!(country.generate_locales(), ["gsw-CH", "de-CH"]);
+        assert_eq!(country.default_locale(), None);
+    }
+}
@@ -1,9 +1,40 @@
+#[cfg(feature = "scrape")]
 pub mod scrape;
+#[cfg(feature = "pull")]
 pub mod pull;
+pub mod error;
+pub mod drift;
+pub mod meta;
+pub mod fallback;
+pub mod merge;
+pub mod lang;
+pub mod currency;
+pub mod money;
+pub mod codegen;
+pub mod codes;
+pub mod types;
+pub mod validate;
+pub mod coverage;
+#[cfg(feature = "formats")]
+pub mod output;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+#[cfg(feature = "data")]
+pub mod data;
+#[cfg(any(feature = "scrape", feature = "pull"))]
+mod http;
 
+pub use error::{Error, Result};
+pub use codes::{CountryCode, CountryCodeA3, CurrencyCode};
+pub use types::{Alpha3, CallingCode, Flag, Tld};
+
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "scrape")]
 use scrape::wikipedia::*;
+#[cfg(feature = "pull")]
 use pull::datahub::*;
 
 pub const WIKIPEDIA_COUNTRIES_URL: &str = "https://en.wikipedia.org/wiki/List_of_ISO_3166_country_codes";
@@ -12,15 +43,92 @@ pub const WIKIPEDIA_CURRENCIES_URL: &str = "https://en.wikipedia.org/wiki/List_o
 pub const DATAHUB_CURRENCIES_URL: &str = "https://datahub.io/core/currency-codes/r/codes-all.json";
 pub const WIKIPEDIA_FLAG_EMOJIS_URL: &str = "https://en.wikipedia.org/wiki/Regional_indicator_symbol";
 pub const WIKIPEDIA_CALLING_CODES_URL: &str = "https://en.wikipedia.org/wiki/List_of_country_calling_codes";
+pub const WIKIPEDIA_OFFICIAL_LANGUAGES_URL: &str = "https://en.wikipedia.org/wiki/List_of_official_languages_by_country_and_territory";
 pub const WIKIPEDIA_ENDONYMS_URL: &str = "https://en.wikipedia.org/wiki/List_of_countries_and_dependencies_and_their_capitals_in_native_languages";
 
+/// Sources the countries data is combined from
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum DataSource {
+    WikipediaCountries,
+    DatahubCountries,
+    WikipediaEndonyms,
+    DatahubCurrencies,
+    WikipediaCurrencies,
+    WikipediaFlagEmojis,
+    WikipediaCallingCodes,
+    WikipediaOfficialLanguages,
+}
+
+impl DataSource {
+    pub fn url(&self) -> &'static str {
+        match self {
+            Self::WikipediaCountries => WIKIPEDIA_COUNTRIES_URL,
+            Self::DatahubCountries => DATAHUB_COUNTRIES_URL,
+            Self::WikipediaEndonyms => WIKIPEDIA_ENDONYMS_URL,
+            Self::DatahubCurrencies => DATAHUB_CURRENCIES_URL,
+            Self::WikipediaCurrencies => WIKIPEDIA_CURRENCIES_URL,
+            Self::WikipediaFlagEmojis => WIKIPEDIA_FLAG_EMOJIS_URL,
+            Self::WikipediaCallingCodes => WIKIPEDIA_CALLING_CODES_URL,
+            Self::WikipediaOfficialLanguages => WIKIPEDIA_OFFICIAL_LANGUAGES_URL,
+        }
+    }
+}
+
+impl Display for DataSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::WikipediaCountries => "wikipedia_countries",
+            Self::DatahubCountries => "datahub_countries",
+            Self::WikipediaEndonyms => "wikipedia_endonyms",
+            Self::DatahubCurrencies => "datahub_currencies",
+            Self::WikipediaCurrencies => "wikipedia_currencies",
+            Self::WikipediaFlagEmojis => "wikipedia_flag_emojis",
+            Self::WikipediaCallingCodes => "wikipedia_calling_codes",
+            Self::WikipediaOfficialLanguages => "wikipedia_official_languages",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
 pub struct Currency {
     pub name: String,
     pub iso_4217: Option<String>,
+    /// ISO 4217 numeric code
+    #[serde(default)]
+    pub numeric_code: Option<u16>,
+    /// Number of decimals of the minor unit, 2 for cents
+    #[serde(default)]
+    pub minor_unit: Option<u8>,
+    /// Primary symbol
     pub symbol: Option<String>,
+    #[serde(default)]
+    pub symbols: Option<Vec<Symbol>>,
+    /// Name of the minor unit
     pub fraction: Option<String>,
     pub fractions_in_unit: Option<i32>,
+    /// Year and month (or just the year) the currency was withdrawn, `2002-03`
+    #[serde(default)]
+    pub withdrawn: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: String,
+    pub kind: SymbolKind,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Primary,
+    /// Symbol without the letters telling currencies of the same sign apart, `$`
+    /// of `C$`
+    Narrow,
+    /// Symbol used for amounts more than one, `Dhs` of `Dh`
+    Plural,
 }
 
 #[derive(Deserialize, Serialize, Debug, Default, Clone)]
@@ -29,124 +137,335 @@ pub struct Time {
     pub dst: Option<String>,
 }
 
+/// Name in the country's own language. Names of older builds written as plain
+/// strings are read as names without a language.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(from = "EndonymRepr")]
+pub struct Endonym {
+    pub name: String,
+    /// BCP 47 language tag, `fi` or `ar-Latn` for example
+    pub lang: Option<String>,
+    /// ISO 15924 script if the language tag names one
+    pub script: Option<String>,
+    /// Name is a romanization of another endonym rather than a name of its own
+    pub romanized: bool,
+}
+
+impl Endonym {
+    pub fn new(name: &str, lang: Option<&str>) -> Self {
+        // Script subtag is the one with four letters, `zh-Hant-TW`
+        let script = lang.and_then(|l|l.split('-').skip(1)
+            .find(|s|s.len() == 4 && s.chars().all(|c|c.is_ascii_alphabetic())))
+            .map(|s|s[..1].to_uppercase() + &s[1..].to_lowercase());
+
+        Self {
+            name: name.to_string(), lang: lang.map(|s|s.to_string()), script,
+            romanized: false
+        }
+    }
+
+    /// Primary language subtag, `ar` of `ar-Latn`
+    pub fn language(&self) -> Option<&str> {
+        self.lang.as_ref().and_then(|l|l.split('-').next())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum EndonymRepr {
+    Plain(String),
+    Tagged {
+        name: String,
+        lang: Option<String>,
+        script: Option<String>,
+        #[serde(default)]
+        romanized: bool,
+    },
+}
+
+impl From<EndonymRepr> for Endonym {
+    fn from(r: EndonymRepr) -> Self {
+        match r {
+            EndonymRepr::Plain(name) => Self { name, ..Self::default() },
+            EndonymRepr::Tagged { name, lang, script, romanized } =>
+                Self { name, lang, script, romanized },
+        }
+    }
+}
+
+/// Language spoken in the country. Languages of older builds written as plain
+/// strings are read as names without codes.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(from = "LanguageRepr")]
+pub struct Language {
+    pub name: String,
+    pub iso_639_1: Option<String>,
+    pub iso_639_3: Option<String>,
+    pub status: Option<LanguageStatus>,
+}
+
+impl Language {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string(), ..Self::default() }
+    }
+}
+
+/// Standing of a language in the country, most prominent first
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum LanguageStatus {
+    /// The one official language
+    Official,
+    /// One of many official languages
+    CoOfficial,
+    /// Recognized as a regional, minority or national language
+    Recognized,
+    /// Widely spoken without a status of its own
+    WidelySpoken,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LanguageRepr {
+    Plain(String),
+    Coded {
+        name: String,
+        iso_639_1: Option<String>,
+        iso_639_3: Option<String>,
+        #[serde(default)]
+        status: Option<LanguageStatus>,
+    },
+}
+
+impl From<LanguageRepr> for Language {
+    fn from(r: LanguageRepr) -> Self {
+        match r {
+            LanguageRepr::Plain(name) => Self { name, ..Self::default() },
+            LanguageRepr::Coded { name, iso_639_1, iso_639_3, status } =>
+                Self { name, iso_639_1, iso_639_3, status },
+        }
+    }
+}
+
+/// Writing system used in the country
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Script {
+    /// ISO 15924 code
+    pub code: String,
+    pub name: String,
+    /// Written right to left
+    pub rtl: bool,
+}
+
 #[derive(Deserialize, Serialize, Default, Debug, Clone)]
 pub struct Country {
+    /// Short english name
     pub exonym: String,
+    /// Formal english name
     pub name: String,
+    /// Other english names the sources know the country by
+    pub aliases: Option<Vec<String>>,
     pub capital: Option<String>,
-    pub iso_3166_1_a3: Option<String>,
-    pub tld: Option<String>,
-    pub flag: Option<String>,
+    /// Names of the capital in the country's own languages
+    pub capital_endonyms: Option<Vec<Endonym>>,
+    /// Same as the key countries are mapped with, see `backfill_alpha2` for builds
+    /// that didn't have it
+    #[serde(default)]
+    pub iso_3166_1_a2: String,
+    pub iso_3166_1_a3: Option<Alpha3>,
+    #[serde(default)]
+    pub iso_3166_1_numeric: Option<u16>,
+    pub tld: Option<Tld>,
+    pub flag: Option<Flag>,
+    /// Currency in use
     pub currency: Option<Currency>,
+    /// Withdrawn currencies, most recently withdrawn first
+    #[serde(default)]
+    pub historical_currencies: Option<Vec<Currency>>,
     pub time: Option<Time>,
-    pub calling_code: Option<String>,
-    pub endonyms: Option<Vec<String>>,
-    pub languages: Option<Vec<String>>,
+    pub calling_code: Option<CallingCode>,
+    /// Names of the country in its own languages
+    pub endonyms: Option<Vec<Endonym>>,
+    pub languages: Option<Vec<Language>>,
+    pub scripts: Option<Vec<Script>>,
+    /// BCP 47 locales of the country, most prominent language first
+    pub locales: Option<Vec<String>>,
 }
 
 impl Country {
+    #[cfg(feature = "scrape")]
     pub fn from_wikipedia(item: WikipediaScrapedCountry) -> Self {
         Self {
+            aliases: Some(vec![item.exonym.to_owned()]),
             exonym: item.exonym, name: item.name, capital: None,
-            iso_3166_1_a3: Some(item.iso_3166_1_a3),
-            tld: match item.tld.starts_with(".") {
-                true => Some(item.tld),
-                false => None,
-            }, flag: None, currency: None,
+            capital_endonyms: None, iso_3166_1_a2: item.iso_3166_1_a2,
+            iso_3166_1_a3: item.iso_3166_1_a3,
+            iso_3166_1_numeric: item.iso_3166_1_numeric,
+            tld: item.tld, flag: None, currency: None, historical_currencies: None,
             time: None, calling_code: None, endonyms: None,
-            languages: None
+            languages: None, scripts: None, locales: None
         }
     }
+    #[cfg(feature = "pull")]
     pub fn from_country_list(item: DatahubCountry) -> Self {
         Self {
-            exonym: item.name.to_owned(), name: item.name, capital: None,
-            iso_3166_1_a3: None, tld: None, flag: None, currency: None,
-            time: None, calling_code: None, endonyms: None,
-            languages: None
+            exonym: item.name.to_owned(), aliases: Some(vec![item.name.to_owned()]),
+            name: item.name, capital: None, capital_endonyms: None,
+            iso_3166_1_a2: item.code, iso_3166_1_a3: None, iso_3166_1_numeric: None,
+            tld: None, flag: None,
+            currency: None, historical_currencies: None, time: None,
+            calling_code: None, endonyms: None,
+            languages: None, scripts: None, locales: None
         }
     }
-    pub fn add_wikipedia_currency(&mut self, item: WikipediaScrapedCurrency) {
-        let mut currency = match self.currency {
-            Some(ref c) => c.to_owned(),
-            None => Currency {
-                name: item.name, iso_4217: item.iso_4217.to_owned(), symbol:
-                item.symbol.to_owned(), fraction: item.fraction.to_owned(),
-                fractions_in_unit: item.fractions_in_unit.to_owned()
-            }
+    /// Currency values wikipedia gives for the country
+    #[cfg(feature = "scrape")]
+    pub fn from_wikipedia_currency(item: WikipediaScrapedCurrency) -> Self {
+        Self {
+            currency: Some(Currency {
+                name: item.name, iso_4217: item.iso_4217, symbol: item.symbol,
+                fraction: item.fraction, fractions_in_unit: item.fractions_in_unit,
+                ..Currency::default()
+            }),
+            ..Self::default()
+        }
+    }
+    /// Currency values of the datahub list, `None` for entities without currency.
+    /// Withdrawn currencies go to the historical ones.
+    #[cfg(feature = "pull")]
+    pub fn from_currency_list(item: DatahubCurrency) -> Option<Self> {
+        let withdrawn = item.withdrawn();
+        let currency = match (item.name, item.iso_4217) {
+            (Some(name), Some(iso_4217)) => Currency {
+                name, iso_4217: Some(iso_4217), numeric_code: item.numeric_code,
+                minor_unit: item.minor_unit, ..Currency::default()
+            },
+            _ => return None,
         };
 
-        if currency.iso_4217.is_none() {
-            currency.iso_4217 = item.iso_4217;
+        match withdrawn {
+            Some(d) => Some(Self {
+                historical_currencies: Some(vec![Currency { withdrawn: Some(d), ..currency }]),
+                ..Self::default()
+            }),
+            None => Some(Self { currency: Some(currency), ..Self::default() }),
         }
-
-        if currency.symbol.is_none() {
-            currency.symbol = item.symbol;
+    }
+    #[cfg(feature = "scrape")]
+    pub fn from_wikipedia_cc_tz(item: WikipediaScrapedCcTz) -> Self {
+        Self {
+            time: Some(Time { zone: item.tz, dst: item.dst }),
+            calling_code: item.code,
+            ..Self::default()
         }
+    }
+    /// Capital, endonyms and languages of the country. English name the page
+    /// uses goes to aliases.
+    #[cfg(feature = "scrape")]
+    pub fn from_wikipedia_endonyms_langs(item: WikipediaScrapedEndonyms) -> Self {
+        let mut langs = item.languages.iter()
+            .map(|s|Language::new(s))
+            .collect::<Vec<_>>();
 
-        if currency.fraction.is_none() {
-            currency.fraction = item.fraction;
-        }
+        // Order of the page tells how prominent the languages are, keep it
+        let mut seen = vec![];
+        langs.retain(|l|match seen.contains(&l.name) {
+            true => false,
+            false => { seen.push(l.name.to_owned()); true },
+        });
 
-        if currency.fractions_in_unit.is_none() {
-            currency.fractions_in_unit = item.fractions_in_unit;
+        Self {
+            aliases: Some(vec![item.exonym]),
+            capital: Some(item.capital),
+            capital_endonyms: match item.capital_endonyms.is_empty() {
+                true => None,
+                false => Some(item.capital_endonyms),
+            },
+            endonyms: match item.endonyms.is_empty() {
+                true => None,
+                false => Some(item.endonyms),
+            },
+            languages: match langs.is_empty() {
+                true => None,
+                false => Some(langs),
+            },
+            ..Self::default()
         }
-
-        self.currency = Some(currency)
     }
-    pub fn add_currency_from_list(&mut self, item: DatahubCurrency) {
-        let mut currency = match self.currency {
-            Some(ref c) => c.to_owned(),
-            None => match item.iso_4217.is_some() && item.name.is_some() {
-                true => Currency {
-                    name: item.name.unwrap(),
-                    iso_4217: Some(item.iso_4217.to_owned().unwrap()),
-                    ..Currency::default()
-                },
-                false => return,
-            }
+    /// Languages of the country with their status. Regional, minority and national
+    /// languages are all taken as recognized ones.
+    #[cfg(feature = "scrape")]
+    pub fn from_wikipedia_official_languages(item: WikipediaScrapedOfficialLanguages) -> Self {
+        let official = match item.official.len() {
+            1 => LanguageStatus::Official,
+            _ => LanguageStatus::CoOfficial,
         };
 
-        if currency.iso_4217.is_none() && item.iso_4217.is_some() {
-            currency.iso_4217 = Some(item.iso_4217.unwrap());
-        }
+        let recognized = item.regional.into_iter()
+            .chain(item.minority)
+            .chain(item.national);
+
+        let mut langs = item.official.into_iter()
+            .map(|s|(s, official))
+            .chain(recognized.map(|s|(s, LanguageStatus::Recognized)))
+            .chain(item.widely_spoken.into_iter().map(|s|(s, LanguageStatus::WidelySpoken)))
+            .map(|(s, status)|Language { status: Some(status), ..Language::new(&s) })
+            .collect::<Vec<_>>();
 
-        self.currency = Some(currency)
+        // Language listed under many statuses keeps the most prominent one,
+        // languages stay in the order the table lists them in
+        let mut seen = vec![];
+        langs.retain(|l|match seen.contains(&l.name) {
+            true => false,
+            false => { seen.push(l.name.to_owned()); true },
+        });
+
+        Self {
+            languages: match langs.is_empty() {
+                true => None,
+                false => Some(langs),
+            },
+            ..Self::default()
+        }
     }
-    pub fn add_wikipedia_cc_tz(&mut self, item: WikipediaScrapedCcTz) {
-        self.time = Some(Time { zone: item.tz, dst: item.dst });
-        self.calling_code = Some(item.code);
+    /// Alpha-2 code as `CountryCode`, `None` for countries newer than the enum
+    pub fn code(&self) -> Option<CountryCode> {
+        self.iso_3166_1_a2.parse().ok()
     }
-    pub fn add_wikipedia_endonyms_langs(&mut self, item: WikipediaScrapedEndonyms) {
-        if self.capital.is_none() {
-            self.capital = Some(item.capital);
-        }
+    /// Every name the country is known by, exonym first
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = vec![self.exonym.as_str(), self.name.as_str()];
 
-        let mut endonyms = item.endonyms.into_iter()
-            .filter(|s|s.ne(&self.exonym))
-            .collect::<Vec<String>>();
+        if let Some(ref v) = self.aliases {
+            names.extend(v.iter().map(|s|s.as_str()));
+        }
 
         if let Some(ref v) = self.endonyms {
-            endonyms.extend(v.to_owned());
+            names.extend(v.iter().map(|e|e.name.as_str()));
         }
 
-        endonyms.sort();
-        endonyms.dedup();
-
-        if ! endonyms.is_empty() {
-            self.endonyms = Some(endonyms);
+        names
+    }
+    /// Sources give their own english names as aliases, drop the ones that
+    /// ended up as the exonym or the name.
+    pub fn tidy_names(&mut self) {
+        if let Some(ref mut v) = self.aliases {
+            v.retain(|s|s.ne(&self.exonym) && s.ne(&self.name));
         }
 
-        let mut langs = item.languages.to_owned();
-
-        if let Some(ref v) = self.languages {
-            langs.extend(v.to_owned());
+        if self.aliases.as_ref().is_some_and(|v|v.is_empty()) {
+            self.aliases = None;
         }
+    }
+}
 
-        langs.sort();
-        langs.dedup();
-
-        if ! langs.is_empty() {
-            self.languages = Some(langs);
+/// Alpha-2 codes the countries are missing taken from the keys they're mapped
+/// with. Builds before the alpha-2 field only had the code as the key, countries
+/// are backfilled once when read so the rest can trust the field.
+pub fn backfill_alpha2<K: Display>(countries: &mut BTreeMap<K, Country>) {
+    for (k, c) in countries.iter_mut() {
+        if c.iso_3166_1_a2.is_empty() {
+            c.iso_3166_1_a2 = k.to_string();
         }
     }
 }
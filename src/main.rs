@@ -1,169 +1,723 @@
 use std::collections::BTreeMap;
 use std::fs::write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+
+use anyhow::{Result, bail};
+use clap::{Args, Parser, Subcommand, ValueEnum};
 
 use bubbles_lib::*;
+use bubbles_lib::coverage::{Coverage, CoverageGate};
+use bubbles_lib::drift::{DriftAction, DriftConfig, DriftGuard, Fingerprint};
+use bubbles_lib::lang::LanguageRegistry;
+use bubbles_lib::merge::{MergePolicy, Merger};
+use bubbles_lib::meta::BuildMeta;
+use bubbles_lib::output::{self, Format};
 use bubbles_lib::pull::datahub::*;
+use bubbles_lib::scrape::{Source, Table};
+use bubbles_lib::scrape::inspect::inspect_tables;
 use bubbles_lib::scrape::wikipedia::*;
+use bubbles_lib::validate::{Report, Rule, Severity};
+
+#[derive(Parser)]
+#[command(version, about = "Combines country information from multiple sources")]
+#[command(args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(flatten)]
+    build: BuildArgs,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Args)]
+struct BuildArgs {
+    /// File to write the combined countries data into
+    #[arg(short, long, default_value = "./out.json")]
+    out: PathBuf,
+    /// Format to write the countries in (json, ndjson, csv, yaml or toml), by the
+    /// extension of the output file if not given
+    #[arg(short, long)]
+    format: Option<Format>,
+    /// Warn or fail when headers or row counts of a source changed since the
+    /// previous build
+    #[arg(long, default_value_t = DriftAction::Warn)]
+    drift: DriftAction,
+    /// Percentage the row count of a source may drop between builds
+    #[arg(long, default_value_t = 10.0)]
+    max_row_drop: f64,
+    /// Leave values of failed sources out instead of taking them from the
+    /// previous build
+    #[arg(long)]
+    no_fallback: bool,
+    /// JSON file ranking the sources per field, see `MergePolicy`
+    #[arg(long)]
+    merge_policy: Option<PathBuf>,
+    /// JSON file of languages and scripts to use instead of the bundled ones
+    #[arg(long)]
+    languages: Option<PathBuf>,
+    /// Fail the build when coverage of a field is below this percentage. `90`
+    /// applies to every field, `flag=99` to one of them.
+    #[arg(long, value_delimiter = ',')]
+    min_coverage: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Scrape and pull every source into out.json (default)
+    Build(BuildArgs),
+    /// List tables found from a web page or html file as the scraper sees them
+    Inspect {
+        /// Url or path to html file
+        source: String,
+        /// Show only the table with this index
+        #[arg(short, long)]
+        table: Option<usize>,
+        /// Number of rows to print from each table
+        #[arg(short, long, default_value_t = 3)]
+        rows: usize,
+        /// Export the table selected with --table
+        #[arg(short, long, value_enum, requires = "table")]
+        export: Option<Export>,
+        /// File to write the export into, stdout if not given
+        #[arg(short, long, requires = "export")]
+        out: Option<PathBuf>,
+    },
+    /// Check that fields of built countries agree with each other
+    Validate {
+        /// Countries built earlier
+        #[arg(short, long, default_value = "./out.json")]
+        input: PathBuf,
+        /// Rules to check, all of them if not given
+        #[arg(short, long, value_delimiter = ',')]
+        rules: Vec<Rule>,
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+        /// Exit with an error if findings this severe or more are found
+        #[arg(long, default_value_t = Severity::Error)]
+        fail_on: Severity,
+    },
+    /// Tell how many countries have a value for each field and what each one
+    /// is missing
+    Coverage {
+        /// Countries built earlier
+        #[arg(short, long, default_value = "./out.json")]
+        input: PathBuf,
+        /// Countries of an earlier build to compare the coverage with
+        #[arg(short, long)]
+        previous: Option<PathBuf>,
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+        /// Exit with an error when coverage of a field is below this percentage
+        #[arg(long, value_delimiter = ',')]
+        min_coverage: Vec<String>,
+    },
+    /// Export built countries for other tools
+    Export {
+        /// What to export into
+        #[arg(value_enum)]
+        target: Target,
+        /// Countries built earlier
+        #[arg(short, long, default_value = "./out.json")]
+        input: PathBuf,
+        /// File to write the export into
+        #[arg(short, long)]
+        out: PathBuf,
+    },
+    /// Generate the country code enums from built countries, currency codes from
+    /// the bundled ISO 4217 list
+    Codegen {
+        /// Countries built earlier
+        #[arg(short, long, default_value = "./out.json")]
+        input: PathBuf,
+        /// File to write the enums into
+        #[arg(short, long, default_value = "./src/codes.rs")]
+        out: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Export {
+    Csv,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Target {
+    /// SQLite database with tables for currencies, languages, names and such
+    Sqlite,
+    /// TypeScript module with interfaces of the model
+    #[value(name = "typescript")]
+    TypeScript,
+    /// Python module with dataclasses of the model
+    Python,
+    /// Rust source with a static of the countries
+    Rust,
+}
 
 fn main() {
-    let mut base = match WikipediaScrapedCountry::scrape(WIKIPEDIA_COUNTRIES_URL) {
-        Ok(v) => v.into_iter()
-            .map(|i|
-                (i.iso_3166_1_a2.to_owned(), Country::from_wikipedia(i))
-            )
-            .collect::<BTreeMap<String, Country>>(),
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        None => build(cli.build),
+        Some(Command::Build(args)) => build(args),
+        Some(Command::Inspect { source, table, rows, export, out }) =>
+            inspect(&source, table, rows, export, out),
+        Some(Command::Validate { input, rules, json, fail_on }) =>
+            validate(&input, rules, json, fail_on),
+        Some(Command::Coverage { input, previous, json, min_coverage }) =>
+            coverage(&input, previous, json, &min_coverage),
+        Some(Command::Export { target, input, out }) => export(target, &input, &out),
+        Some(Command::Codegen { input, out }) => codegen(&input, &out),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        exit(1);
+    }
+}
+
+fn build(args: BuildArgs) -> Result<()> {
+    // Previous build tells us how the sources looked like last time
+    let meta_path = BuildMeta::path_for(&args.out);
+    let mut meta = BuildMeta::read(&meta_path)?.unwrap_or_default();
+    let config = DriftConfig { action: args.drift, max_row_drop: args.max_row_drop };
+    let mut guard = DriftGuard::new(config, meta.fingerprints());
+
+    let format = args.format
+        .or(Format::from_path(&args.out))
+        .unwrap_or_default();
+
+    // Flattened CSV rows can't be read back into countries
+    let last = match format {
+        Format::Csv => {
+            println!("Previous build is not read from CSV, write JSON to fall back on it");
+            None
+        },
+        _ => format.read_previous(&args.out)?,
+    };
+
+    // Failed sources fall back to what they gave in the previous build
+    let previous = match args.no_fallback {
+        true => None,
+        false => last.to_owned(),
+    };
+    let mut stale = vec![];
+
+    // Coverage of the previous build to tell how this one did compared to it
+    let gate = match CoverageGate::from_args(&args.min_coverage) {
+        Ok(g) => g,
+        Err(e) => bail!(e),
+    };
+    let last_coverage = last.as_ref().map(Coverage::new);
+
+    let policy = match args.merge_policy {
+        Some(ref p) => MergePolicy::read(p)?,
+        None => MergePolicy::recommended(),
+    };
+
+    // Sources only add their values here, the policy decides which ones we keep
+    let mut merger = Merger::default();
+
+    match scraped(&mut guard, DataSource::WikipediaCountries,
+    WikipediaScrapedCountry::tables, WikipediaScrapedCountry::from_tables) {
+        Ok(v) => for i in v {
+            merger.add(&i.iso_3166_1_a2.to_owned(), DataSource::WikipediaCountries,
+                Country::from_wikipedia(i));
+        },
         Err(e) => {    
             eprintln!("Failed to build base data from wikipedia: {}", e);
-
-            BTreeMap::new()
+            fall_back(&mut merger, &previous, &mut stale, DataSource::WikipediaCountries);
         },
     };
 
     // We either have the base data or not. Start extending it
-    match DatahubCountry::pull(DATAHUB_COUNTRIES_URL) {
+    match pulled(&mut guard, DataSource::DatahubCountries, DatahubCountry::from_json) {
         Ok(v) => {
+            // The name field would be great if it would be written as the
+            // short english exonym as the page itself (wikipedia..) but no.
+            // This list might have english exonyms instead.
             for i in v {
-                match base.get_mut(&i.code) {
-                    Some(c) => {
-                        // The name field would be great if it would be written as
-                        // the short english exonym as the page itself (wikipedia..)
-                        // but no.
-                        // This list might have english exonyms instead.
-                        if c.exonym.is_empty() || c.exonym.ne(&i.name) {
-                            let mut endonyms = match c.endonyms {
-                                Some(ref v) => v.to_owned(),
-                                None => vec![],
-                            };
-
-                            // Take the overwritten value into the endonyms
-                            if ! endonyms.contains(&c.exonym) {
-                                endonyms.push(c.exonym.to_owned());
-                                c.endonyms = Some(endonyms);
-                            }
-                            
-                            c.exonym = i.name.to_owned();
-                        }
-
-                        if c.name.is_empty() {
-                            c.name = i.name;
-                        }
-                    },
-                    None => {
-                        base.insert(
-                            i.code.to_owned(),
-                            Country::from_country_list(i)
-                        );
-                    }
-                }
+                merger.add(&i.code.to_owned(), DataSource::DatahubCountries,
+                    Country::from_country_list(i));
             }
         },
-        Err(e) => eprintln!("Failed to pull country data from datahub: {}", e),
+        Err(e) => {
+            eprintln!("Failed to pull country data from datahub: {}", e);
+            fall_back(&mut merger, &previous, &mut stale, DataSource::DatahubCountries);
+        },
     };
 
     // Since many datasets refer countries by their 'names' and not by their ISO codes
     // or their exonyms more names we have the merrier.
-    match WikipediaScrapedEndonyms::scrape(WIKIPEDIA_ENDONYMS_URL) {
+    match scraped(&mut guard, DataSource::WikipediaEndonyms,
+    WikipediaScrapedEndonyms::tables, WikipediaScrapedEndonyms::from_tables) {
         Ok(v) => {
+            let base = merger.countries(&policy);
+
             for c in v {
-                match base.iter_mut().find(|(_, b)|b.exonym.to_lowercase().eq(&c.exonym.to_lowercase())) {
-                    Some((_, i)) => i.add_wikipedia_endonyms_langs(c),
-                    None => match endonym_match(&mut base, c.exonym.to_string()) {
-                        Some(i) => i.add_wikipedia_endonyms_langs(c),
-                        None => eprintln!("Skip adding endonyms {:?} and languages {:?} \
+                match find_country(&base, &c.exonym) {
+                    Some((k, _)) => merger.add(k, DataSource::WikipediaEndonyms,
+                        Country::from_wikipedia_endonyms_langs(c)),
+                    None => eprintln!("Skip adding endonyms {:?} and languages {:?} \
                         to unknown country {}", c.endonyms, c.languages, c.exonym),
-                    }
                 }
             }
         },
-        Err(e) => eprintln!("Failed to add endonyms data from wikipedia: {}", e),
+        Err(e) => {
+            eprintln!("Failed to add endonyms data from wikipedia: {}", e);
+            fall_back(&mut merger, &previous, &mut stale, DataSource::WikipediaEndonyms);
+        },
     }
 
-    if base.len() == 0 {
-        panic!("Well that went well. Not even one country was found.")
+    let base = merger.countries(&policy);
+
+    if base.is_empty() {
+        bail!("Well that went well. Not even one country was found.")
     }
 
-    // Try to take currencies first from this list which has at least some of the
-    // names in their short form
-    match DatahubCurrency::pull(DATAHUB_CURRENCIES_URL) {
+    // Datahub currency list has at least some of the country names in their short
+    // form. Which of the currency sources wins is up to the merge policy.
+    match pulled(&mut guard, DataSource::DatahubCurrencies, DatahubCurrency::from_json) {
         Ok(v) => {
             for c in v {
-                match base.iter_mut().find(|(_, b)|b.exonym.to_lowercase().eq(&c.exonym.to_lowercase())) {
-                    Some((_, i)) => i.add_currency_from_list(c),
-                    None => match endonym_match(&mut base, c.exonym.to_string()) {
-                        Some(i) => i.add_currency_from_list(c),
-                        None => eprintln!("Skip adding currency {:?} to unknown country {}",
-                            c.name, c.exonym),
-                    }
+                let exonym = c.exonym.to_owned();
+                let name = c.name.to_owned();
+
+                match (find_country(&base, &exonym), Country::from_currency_list(c)) {
+                    (Some((k, _)), Some(i)) => merger.add(k, DataSource::DatahubCurrencies, i),
+                    (Some(_), None) => (),
+                    (None, _) => eprintln!("Skip adding currency {:?} to unknown country {}",
+                        name, exonym),
                 }
             }
         },
-        Err(e) => eprintln!("Failed to pull currency data from datahub: {}", e),
+        Err(e) => {
+            eprintln!("Failed to pull currency data from datahub: {}", e);
+            fall_back(&mut merger, &previous, &mut stale, DataSource::DatahubCurrencies);
+        },
     }
 
     // Add currencies if we can a. pull the source data b. have existing country item
     // to match against
-    match WikipediaScrapedCurrency::scrape(WIKIPEDIA_CURRENCIES_URL) {
+    match scraped(&mut guard, DataSource::WikipediaCurrencies,
+    WikipediaScrapedCurrency::tables, WikipediaScrapedCurrency::from_tables) {
         Ok(v) => {
             for c in v {
-                match base.iter_mut().find(|(_, b)|b.exonym.to_lowercase().eq(&c.exonym.to_lowercase())) {
-                    Some((_, i)) => i.add_wikipedia_currency(c),
-                    None => match endonym_match(&mut base, c.exonym.to_string()) {
-                        Some(i) => i.add_wikipedia_currency(c),
-                        None => eprintln!("Skip adding currency {} to unknown country {}",
-                            c.name, c.exonym),
-                    }
+                match find_country(&base, &c.exonym) {
+                    Some((k, _)) => merger.add(k, DataSource::WikipediaCurrencies,
+                        Country::from_wikipedia_currency(c)),
+                    None => eprintln!("Skip adding currency {} to unknown country {}",
+                        c.name, c.exonym),
                 }
             }
         },
-        Err(e) => eprintln!("Failed to add currency data from wikipedia: {}", e),
+        Err(e) => {
+            eprintln!("Failed to add currency data from wikipedia: {}", e);
+            fall_back(&mut merger, &previous, &mut stale, DataSource::WikipediaCurrencies);
+        },
     }
 
     // Add flag emojis scraping wikipedia again
-    match WikipediaScrapedFlag::scrape(WIKIPEDIA_FLAG_EMOJIS_URL) {
+    match scraped(&mut guard, DataSource::WikipediaFlagEmojis,
+    WikipediaScrapedFlag::tables, WikipediaScrapedFlag::from_tables) {
         Ok(v) => {
             for i in v {
-                match base.get_mut(&i.iso_3166_1_a2) {
-                    Some(c) => { c.flag = Some(i.flag); },
-                    None => {
+                match base.contains_key(&i.iso_3166_1_a2) {
+                    true => merger.add(&i.iso_3166_1_a2, DataSource::WikipediaFlagEmojis,
+                        Country { flag: Some(i.flag), ..Country::default() }),
+                    false => {
                         eprintln!("Skip adding flag {} to unknown country {}",
                             i.flag, i.iso_3166_1_a2);
                     }
                 }
             }
         },
-        Err(e) => eprintln!("Failed to add emoji flag data from wikipedia: {}", e),
+        Err(e) => {
+            eprintln!("Failed to add emoji flag data from wikipedia: {}", e);
+            fall_back(&mut merger, &previous, &mut stale, DataSource::WikipediaFlagEmojis);
+        },
     }
 
-    match WikipediaScrapedCcTz::scrape(WIKIPEDIA_CALLING_CODES_URL) {
+    match scraped(&mut guard, DataSource::WikipediaCallingCodes,
+    WikipediaScrapedCcTz::tables, WikipediaScrapedCcTz::from_tables) {
         Ok(v) => {
             for c in v {
-                match base.iter_mut().find(|(_, b)|b.exonym.to_lowercase().eq(&c.exonym.to_lowercase())) {
-                    Some((_, i)) => i.add_wikipedia_cc_tz(c),
-                    None => match endonym_match(&mut base, c.exonym.to_string()) {
-                        Some(i) => i.add_wikipedia_cc_tz(c),
-                        None => eprintln!("Skip adding calling code {} and timezone {} \
-                            to unknown country {}", c.code, c.tz, c.exonym),
-                    }
+                match find_country(&base, &c.exonym) {
+                    Some((k, _)) => merger.add(k, DataSource::WikipediaCallingCodes,
+                        Country::from_wikipedia_cc_tz(c)),
+                    None => eprintln!("Skip adding calling code {} and timezone {} \
+                        to unknown country {}", c.code.as_ref().map(|s|s.as_str()).unwrap_or("-"),
+                        c.tz, c.exonym),
                 }
             }
         },
-        Err(e) => eprintln!("Failed to add calling code and timezone data \
-            from wikipedia: {}", e),
+        Err(e) => {
+            eprintln!("Failed to add calling code and timezone data \
+                from wikipedia: {}", e);
+            fall_back(&mut merger, &previous, &mut stale, DataSource::WikipediaCallingCodes);
+        },
+    }
+
+    // Official, co-official and recognized languages. Which of the languages listed
+    // above get a status.
+    match scraped(&mut guard, DataSource::WikipediaOfficialLanguages,
+    WikipediaScrapedOfficialLanguages::tables, WikipediaScrapedOfficialLanguages::from_tables) {
+        Ok(v) => {
+            for c in v {
+                match find_country(&base, &c.exonym) {
+                    Some((k, _)) => merger.add(k, DataSource::WikipediaOfficialLanguages,
+                        Country::from_wikipedia_official_languages(c)),
+                    None => eprintln!("Skip adding official languages {:?} to unknown \
+                        country {}", c.official, c.exonym),
+                }
+            }
+        },
+        Err(e) => {
+            eprintln!("Failed to add official languages data from wikipedia: {}", e);
+            fall_back(&mut merger, &previous, &mut stale, DataSource::WikipediaOfficialLanguages);
+        },
+    }
+
+    let (mut base, conflicts) = merger.merge(&policy);
+
+    for c in conflicts.iter() {
+        eprintln!("{}", c);
+    }
+
+    // Language names into ISO 639 codes, the scripts they're written in and the
+    // locales of the country
+    let registry = match args.languages {
+        Some(ref p) => LanguageRegistry::read(p)?,
+        None => LanguageRegistry::bundled(),
+    };
+
+    // Currencies replacing withdrawn ones take their values from countries using them
+    let in_use = currency::currencies_in_use(&base);
+
+    for (k, c) in base.iter_mut() {
+        c.resolve_languages(&registry);
+
+        if let Some((x, n)) = c.retire_unlisted_currency(&in_use) {
+            println!("Replaced currency {} of {} with {}, it's not on the ISO 4217 list", x, k, n);
+        }
+
+        if let Some(ref mut x) = c.currency {
+            x.resolve_symbols();
+        }
+
+        for x in c.currency.iter_mut().chain(c.historical_currencies.iter_mut().flatten()) {
+            x.resolve_listed();
+        }
+
+        c.tidy_currencies();
+    }
+
+    for i in currency::validate_currencies(&base) {
+        eprintln!("{}", i);
+    }
+
+    let report = Report::new(&base, &Rule::ALL);
+
+    for i in report.findings.iter() {
+        eprintln!("{}", i);
+    }
+
+    for (k, c) in base.iter() {
+        if c.code().is_none() {
+            eprintln!("Country code {} is not in CountryCode, run codegen", k);
+        }
+
+        if let Some(x) = c.currency.as_ref().filter(|x|x.iso_4217.is_some() && x.code().is_none()) {
+            eprintln!("Currency code {:?} of {} is not in CurrencyCode, run codegen",
+                x.iso_4217, k);
+        }
+    }
+
+    let coverage = Coverage::new(&base);
+
+    println!("{}", coverage);
+
+    if let Some(ref c) = last_coverage {
+        for (f, p, n) in coverage.changes(c) {
+            println!("Coverage of {} changed from {:.1}% to {:.1}%", f, p, n);
+        }
+    }
+
+    // Drifted sources and missing values fail the build before anything gets written
+    guard.finish()?;
+
+    let below = gate.check(&coverage);
+
+    if ! below.is_empty() {
+        bail!("Coverage is below the minimum on {}", below.iter()
+            .map(|(f, p, m)|format!("{} ({:.1}% < {}%)", f, p, m))
+            .collect::<Vec<_>>()
+            .join(", "));
+    }
+
+    let data = output::to_string(format, &base)?;
+
+    if let Err(e) = write(&args.out, data.as_bytes()) {
+        bail!("Failed to write countries {} to {}: {}", format, args.out.display(), e);
+    }
+
+    // Sources that failed this time keep their fingerprints from the previous build
+    meta.sources.extend(BuildMeta::from_fingerprints(&guard.current).sources);
+
+    for s in stale {
+        meta.mark_stale(s);
+    }
+
+    meta.write(&meta_path)?;
+    coverage.write(&Coverage::path_for(&args.out))?;
+
+    Ok(())
+}
+
+/// Source failed, take the values it provided from the previous build if we have one.
+/// Sources that got values restored are stale.
+fn fall_back(merger: &mut Merger, previous: &Option<BTreeMap<String, Country>>,
+stale: &mut Vec<DataSource>, source: DataSource) {
+    let p = match previous {
+        Some(p) => p,
+        None => return,
+    };
+
+    let n = fallback::restore(merger, p, source);
+
+    println!("Restored {} values of {} countries from the previous build", source, n);
+
+    if n > 0 {
+        stale.push(source);
+    }
+}
+
+/// Read tables of the source and check their shape before reading data from them
+fn scraped<T>(guard: &mut DriftGuard, source: DataSource,
+tables: fn(Source<'_>) -> bubbles_lib::Result<Vec<Table>>,
+from_tables: fn(&[Table]) -> bubbles_lib::Result<Vec<T>>) -> bubbles_lib::Result<Vec<T>> {
+    let tables = tables(Source::Url(source.url()))?;
+
+    guard.check(source, Fingerprint::of_tables(&tables))?;
+    from_tables(&tables)
+}
+
+/// Pull JSON records of the source and check their shape before reading them
+fn pulled<T>(guard: &mut DriftGuard, source: DataSource,
+from_json: fn(&str, &str) -> bubbles_lib::Result<Vec<T>>) -> bubbles_lib::Result<Vec<T>> {
+    println!("Pull {} data from {}...", source, source.url());
+
+    let json = pull::fetch(source.url())?;
+
+    guard.check(source, Fingerprint::of_json(source.url(), &json)?)?;
+    from_json(source.url(), &json)
+}
+
+/// Find country by its exonym, or failing that by any of its other names
+fn find_country<'a>(base: &'a BTreeMap<String, Country>, name: &str) -> Option<(&'a String, &'a Country)> {
+    let name = name.to_lowercase();
+
+    base.iter()
+        .find(|(_, b)|b.exonym.to_lowercase().eq(&name))
+        .or_else(||base.iter().find(|(_, b)|b.names().iter().any(|s|s.to_lowercase().eq(&name))))
+}
+
+/// Countries built earlier in the format of the file extension, JSON by default
+fn read_countries(path: &Path) -> Result<BTreeMap<String, Country>> {
+    let format = Format::from_path(path).unwrap_or_default();
+
+    match format.read_previous(path)? {
+        Some(c) => Ok(c),
+        None => bail!("No countries found from {}, build them first", path.display()),
+    }
+}
+
+fn validate(input: &Path, rules: Vec<Rule>, json: bool, fail_on: Severity) -> Result<()> {
+    let countries = read_countries(input)?;
+
+    let rules = match rules.is_empty() {
+        true => Rule::ALL.to_vec(),
+        false => rules,
+    };
+
+    let report = Report::new(&countries, &rules);
+
+    match json {
+        true => match serde_json::to_string_pretty(&report) {
+            Ok(s) => println!("{}", s),
+            Err(e) => bail!("Failed to write validation report to JSON: {}", e),
+        },
+        false => println!("{}", report),
+    }
+
+    if report.fails(fail_on) {
+        bail!("Countries of {} have findings of {} severity or more", input.display(), fail_on);
+    }
+
+    Ok(())
+}
+
+fn coverage(input: &Path, previous: Option<PathBuf>, json: bool, min_coverage: &[String])
+-> Result<()> {
+    let gate = match CoverageGate::from_args(min_coverage) {
+        Ok(g) => g,
+        Err(e) => bail!(e),
+    };
+
+    let coverage = Coverage::new(&read_countries(input)?);
+
+    match json {
+        true => match serde_json::to_string_pretty(&coverage) {
+            Ok(s) => println!("{}", s),
+            Err(e) => bail!("Failed to write coverage report to JSON: {}", e),
+        },
+        false => println!("{}\n\n{}", coverage, coverage.missing_table()),
     }
 
-    let file = PathBuf::from("./out.json");
-    let json = serde_json::to_string_pretty(&base)
-        .expect("Failed to read countries data to JSON string");
+    if let Some(ref p) = previous {
+        let last = Coverage::new(&read_countries(p)?);
+
+        for (f, p, n) in coverage.changes(&last) {
+            eprintln!("Coverage of {} changed from {:.1}% to {:.1}%", f, p, n);
+        }
+    }
+
+    let below = gate.check(&coverage);
+
+    if ! below.is_empty() {
+        bail!("Coverage is below the minimum on {}", below.iter()
+            .map(|(f, p, m)|format!("{} ({:.1}% < {}%)", f, p, m))
+            .collect::<Vec<_>>()
+            .join(", "));
+    }
 
-    write(&file, json.as_bytes())
-        .expect("Failed to write countries json to string");
+    Ok(())
 }
 
-fn endonym_match<'a>(base: &'a mut BTreeMap<String, Country>, exonym: String) -> Option<&'a mut Country> {
-    base.values_mut().find(|b|
-        b.endonyms.is_some() && b.endonyms.to_owned().unwrap().into_iter()
-        .map(|s|s.to_lowercase()).collect::<Vec<String>>()
-        .contains(&exonym.to_lowercase())
-    )
-}
\ No newline at end of file
+fn export(target: Target, input: &Path, out: &Path) -> Result<()> {
+    let countries = read_countries(input)?;
+
+    let write_source = |source: String| -> Result<()> {
+        if let Err(e) = write(out, source) {
+            bail!("Failed to write countries to {}: {}", out.display(), e);
+        }
+
+        Ok(())
+    };
+
+    match target {
+        Target::Sqlite => sqlite::export(&countries, out)?,
+        Target::TypeScript => write_source(codegen::typescript_source(&countries))?,
+        Target::Python => write_source(codegen::python_source(&countries))?,
+        Target::Rust => write_source(codegen::rust_source(&countries))?,
+    }
+
+    println!("Exported {} countries to {}", countries.len(), out.display());
+
+    Ok(())
+}
+
+fn codegen(input: &Path, out: &Path) -> Result<()> {
+    let countries = read_countries(input)?;
+
+    if let Err(e) = write(out, codegen::codes_source(&countries)) {
+        bail!("Failed to write code enums to {}: {}", out.display(), e);
+    }
+
+    println!("Wrote code enums of {} countries to {}", countries.len(), out.display());
+
+    Ok(())
+}
+
+fn inspect(source: &str, index: Option<usize>, rows: usize, export: Option<Export>,
+out: Option<PathBuf>) -> Result<()> {
+    let tables = match source.starts_with("http://") || source.starts_with("https://") {
+        true => inspect_tables(Source::Url(source))?,
+        false => inspect_tables(Source::File(Path::new(source)))?,
+    };
+
+    if tables.is_empty() {
+        bail!("No tables found from {}", source);
+    }
+
+    let tables = match index {
+        Some(i) => match tables.into_iter().find(|t|t.index == i) {
+            Some(t) => vec![t],
+            None => bail!("Table {} not found from {}", i, source),
+        },
+        None => tables,
+    };
+
+    if let Some(x) = export {
+        let data = match x {
+            Export::Csv => tables[0].to_csv()?,
+            Export::Json => tables[0].to_json()?,
+        };
+
+        match out {
+            Some(p) => if let Err(e) = write(&p, data.as_bytes()) {
+                bail!("Failed to write table export to {}: {}", p.display(), e);
+            },
+            None => print!("{}", data),
+        }
+
+        return Ok(())
+    }
+
+    for t in tables {
+        println!("Table {}", t.index);
+
+        if let Some(ref s) = t.heading {
+            println!("  heading: {}", s);
+        }
+
+        if let Some(ref s) = t.caption {
+            println!("  caption: {}", s);
+        }
+
+        if let Some(ref s) = t.id {
+            println!("  id: {}", s);
+        }
+
+        if ! t.classes.is_empty() {
+            println!("  classes: {}", t.classes.join(" "));
+        }
+
+        println!("  columns: {}", t.columns());
+        println!("  headers: {}", t.headers.values().cloned().collect::<Vec<_>>().join(" | "));
+        println!("  rows: {}", t.rows.len());
+
+        for i in 0..rows.min(t.rows.len()) {
+            println!("    {}", t.row_values(i).join(" | "));
+        }
+
+        println!();
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources_are_stale_only_when_values_were_restored() {
+        let fi = Country { exonym: "Finland".into(), iso_3166_1_a2: "FI".into(), ..Country::default() };
+        let previous = Some([("FI".to_string(), fi)].into_iter().collect::<BTreeMap<_, _>>());
+        let mut merger = Merger::default();
+        let mut stale = vec![];
+
+        fall_back(&mut merger, &None, &mut stale, DataSource::WikipediaCountries);
+        fall_back(&mut merger, &previous, &mut stale, DataSource::WikipediaFlagEmojis);
+
+        assert!(stale.is_empty());
+
+        fall_back(&mut merger, &previous, &mut stale, DataSource::WikipediaCountries);
+
+        assert_eq!(stale, vec![DataSource::WikipediaCountries]);
+        assert!(merger.contains("FI"));
+    }
+}
@@ -0,0 +1,558 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::{backfill_alpha2, Country, DataSource};
+use crate::error::{Error, Result};
+
+/// How values of a field given by more than one source are combined
+#[derive(Deserialize, Serialize, Debug, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Strategy {
+    /// Value of the highest ranking source
+    #[default]
+    FirstWins,
+    /// Value of the lowest ranking source
+    LastWins,
+    /// Lists of every source combined, other values as with `FirstWins`
+    Union,
+    /// Sources must give the same value, disagreeing ones leave the field empty
+    RequireAgreement,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct FieldPolicy {
+    /// Sources in order of preference. Sources not listed rank below the listed
+    /// ones in the order they are read.
+    #[serde(default)]
+    pub sources: Vec<DataSource>,
+    #[serde(default)]
+    pub strategy: Strategy,
+}
+
+/// Merge policy of country fields. Fields are named as in the output, nested ones
+/// as `currency.symbol`. Policy of `currency` applies to all of its fields unless
+/// they have one of their own.
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct MergePolicy {
+    #[serde(default)]
+    pub default: FieldPolicy,
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldPolicy>,
+}
+
+impl MergePolicy {
+    /// Policy with source rankings we've found to work best
+    pub fn recommended() -> Self {
+        use DataSource::*;
+
+        let fields = [
+            // Datahub names tend to be the short english exonyms
+            ("exonym", vec![DatahubCountries, WikipediaCountries], Strategy::FirstWins),
+            ("name", vec![WikipediaCountries, DatahubCountries], Strategy::FirstWins),
+            ("aliases", vec![], Strategy::Union),
+            ("endonyms", vec![], Strategy::Union),
+            ("capital_endonyms", vec![], Strategy::Union),
+            // Official languages table lists the languages by their status
+            ("languages", vec![WikipediaOfficialLanguages, WikipediaEndonyms], Strategy::Union),
+            ("currency.name", vec![DatahubCurrencies, WikipediaCurrencies], Strategy::FirstWins),
+            ("currency.iso_4217", vec![DatahubCurrencies, WikipediaCurrencies], Strategy::FirstWins),
+            ("currency", vec![WikipediaCurrencies], Strategy::FirstWins),
+            ("historical_currencies", vec![], Strategy::Union),
+            ("flag", vec![], Strategy::LastWins),
+            ("time", vec![], Strategy::LastWins),
+            ("calling_code", vec![], Strategy::LastWins),
+        ];
+
+        Self {
+            default: FieldPolicy::default(),
+            fields: fields.into_iter()
+                .map(|(f, sources, strategy)|(f.to_string(), FieldPolicy { sources, strategy }))
+                .collect(),
+        }
+    }
+
+    /// Read policy from JSON file. Fields given in the file replace the ones of
+    /// the recommended policy.
+    pub fn read(path: &Path) -> Result<Self> {
+        let json = read_to_string(path)
+            .map_err(|e| Error::File { path: path.to_owned(), source: e })?;
+
+        let policy = serde_json::from_str::<Self>(&json)
+            .map_err(|e| Error::Json { url: path.display().to_string(), source: e })?;
+
+        let mut merged = Self::recommended();
+        merged.default = policy.default;
+        merged.fields.extend(policy.fields);
+
+        Ok(merged)
+    }
+
+    pub fn field(&self, path: &str) -> &FieldPolicy {
+        if let Some(p) = self.fields.get(path) {
+            return p
+        }
+
+        match path.split_once('.').and_then(|(parent, _)|self.fields.get(parent)) {
+            Some(p) => p,
+            None => &self.default,
+        }
+    }
+}
+
+/// Field on which sources did not agree, or whose merged value is not valid
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub country: String,
+    pub field: String,
+    pub values: Vec<(DataSource, Value)>,
+    /// Why the merged value was left out, `None` if the sources just disagreed
+    pub error: Option<String>,
+}
+
+impl Display for Conflict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let values = self.values.iter()
+            .map(|(s, v)|format!("{} says {}", s, v))
+            .collect::<Vec<_>>();
+
+        match self.error {
+            Some(ref e) => write!(f, "Merged {} of {} is not valid ({}): {}", self.field,
+                self.country, e, values.join(", ")),
+            None => write!(f, "Sources disagree on {} of {}: {}", self.field, self.country,
+                values.join(", ")),
+        }
+    }
+}
+
+/// Everything each source had to say about each country. Sources only add values
+/// here, which of them end up in the output is decided by `MergePolicy`. Countries
+/// are keyed by alpha-2 codes as strings, sources may bring codes the code enums
+/// don't know until they're generated from the build.
+#[derive(Debug, Default, Clone)]
+pub struct Merger {
+    contributions: BTreeMap<String, Vec<(DataSource, Country)>>,
+}
+
+impl Merger {
+    /// Add values the source gives for the country. Fields left empty on the given
+    /// country are not taken as values.
+    pub fn add(&mut self, key: &str, source: DataSource, values: Country) {
+        self.contributions.entry(key.to_string())
+            .or_default()
+            .push((source, values));
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.contributions.contains_key(key)
+    }
+
+    pub fn merge(&self, policy: &MergePolicy) -> (BTreeMap<String, Country>, Vec<Conflict>) {
+        let mut countries = BTreeMap::new();
+        let mut conflicts = vec![];
+
+        for (k, v) in self.contributions.iter() {
+            let (country, c) = merge_country(k, v, policy);
+
+            countries.insert(k.to_owned(), country);
+            conflicts.extend(c);
+        }
+
+        // Sources that only key the countries by their codes
+        backfill_alpha2(&mut countries);
+
+        (countries, conflicts)
+    }
+
+    /// Merged countries, conflicts left out
+    pub fn countries(&self, policy: &MergePolicy) -> BTreeMap<String, Country> {
+        self.merge(policy).0
+    }
+}
+
+fn merge_country(key: &str, contributions: &[(DataSource, Country)], policy: &MergePolicy)
+-> (Country, Vec<Conflict>) {
+    let mut contributions = contributions.iter()
+        .map(|(s, c)|(*s, match serde_json::to_value(c) {
+            Ok(Value::Object(m)) => m,
+            _ => Map::new(),
+        }))
+        .collect::<Vec<_>>();
+
+    for (record, k) in RECORDS {
+        pick_record(&mut contributions, record, k, policy);
+    }
+
+    // Field paths with their values from each source
+    let mut values = BTreeMap::<String, Vec<(DataSource, Value)>>::new();
+
+    for (s, m) in contributions.into_iter() {
+        for (path, v) in flatten(m) {
+            values.entry(path).or_default().push((s, v));
+        }
+    }
+
+    let mut merged = Map::new();
+    let mut conflicts = vec![];
+
+    for (path, candidates) in values.iter() {
+        let mut candidates = candidates.to_owned();
+        let field = policy.field(path);
+
+        // Stable sort keeps the read order within the same rank
+        candidates.sort_by_key(|(s, _)|rank(field, s));
+
+        let value = match field.strategy {
+            Strategy::FirstWins => candidates.first().map(|(_, v)|v.to_owned()),
+            Strategy::LastWins => candidates.last().map(|(_, v)|v.to_owned()),
+            Strategy::Union => union(&candidates),
+            Strategy::RequireAgreement => match candidates.iter().all(|(_, v)|v.eq(&candidates[0].1)) {
+                true => candidates.first().map(|(_, v)|v.to_owned()),
+                false => {
+                    conflicts.push(Conflict {
+                        country: key.to_string(), field: path.to_owned(),
+                        values: candidates.to_owned(), error: None
+                    });
+                    None
+                },
+            },
+        };
+
+        if let Some(v) = value {
+            unflatten(&mut merged, path, v);
+        }
+    }
+
+    // Whatever was not given by any source takes the default value
+    let defaults = match serde_json::to_value(Country::default()) {
+        Ok(Value::Object(m)) => m,
+        _ => Map::new(),
+    };
+
+    let mut all = defaults.to_owned();
+    all.extend(merged.to_owned());
+
+    let mut country = match serde_json::from_value::<Country>(Value::Object(all)) {
+        Ok(c) => c,
+        // Values put together from many sources may not make a valid field, say a
+        // currency without a name. Those fields are left out, the rest is kept.
+        Err(_) => {
+            let mut valid = defaults;
+            let mut country = Country::default();
+
+            for (field, v) in merged.into_iter() {
+                let mut m = valid.to_owned();
+                m.insert(field.to_owned(), v);
+
+                match serde_json::from_value::<Country>(Value::Object(m.to_owned())) {
+                    Ok(c) => {
+                        valid = m;
+                        country = c;
+                    },
+                    Err(e) => conflicts.push(Conflict {
+                        country: key.to_string(),
+                        values: values.iter()
+                            .filter(|(p, _)|p.eq(&&field) || p.starts_with(&format!("{}.", field)))
+                            .flat_map(|(_, v)|v.to_owned())
+                            .collect(),
+                        field, error: Some(e.to_string())
+                    }),
+                }
+            }
+
+            country
+        },
+    };
+
+    country.tidy_names();
+
+    (country, conflicts)
+}
+
+/// Records given by many sources are taken from one record as a whole, values of
+/// records with other keys are left out. A currency takes its symbol from the same
+/// currency its code came from.
+const RECORDS: &[(&str, &str)] = &[("currency", "iso_4217"), ("time", "zone")];
+
+/// Rank of the source in the field policy, sources not listed rank last
+fn rank(field: &FieldPolicy, source: &DataSource) -> usize {
+    match field.sources.iter().position(|x|x.eq(source)) {
+        Some(i) => i,
+        None => field.sources.len(),
+    }
+}
+
+/// Drop records of the field whose key differs from the one picked. Key given by
+/// the most sources is picked, ties go by the policy of the key field.
+fn pick_record(contributions: &mut [(DataSource, Map<String, Value>)], record: &str, key: &str,
+policy: &MergePolicy) {
+    let key_of = |m: &Map<String, Value>|m.get(record)
+        .and_then(|r|r.get(key))
+        .filter(|v|has_value(v))
+        .cloned();
+
+    let field = policy.field(&format!("{}.{}", record, key));
+    let mut keys = contributions.iter()
+        .filter_map(|(s, m)|key_of(m).map(|k|(*s, k)))
+        .collect::<Vec<_>>();
+
+    keys.sort_by_key(|(s, _)|rank(field, s));
+
+    if field.strategy == Strategy::LastWins {
+        keys.reverse();
+    }
+
+    let sources = |k: &Value|keys.iter()
+        .filter(|(_, x)|x.eq(k))
+        .map(|(s, _)|*s)
+        .collect::<BTreeSet<_>>()
+        .len();
+
+    let mut picked: Option<(&Value, usize)> = None;
+
+    for (_, k) in keys.iter() {
+        let n = sources(k);
+
+        if picked.is_none_or(|(_, m)|n > m) {
+            picked = Some((k, n));
+        }
+    }
+
+    let picked = match picked {
+        Some((k, _)) => k.to_owned(),
+        None => return,
+    };
+
+    for (_, m) in contributions.iter_mut() {
+        if m.contains_key(record) && key_of(m).as_ref() != Some(&picked) {
+            m.remove(record);
+        }
+    }
+}
+
+/// Fields of the country with values as `path => value`, nested objects are
+/// flattened one level down.
+fn flatten(map: Map<String, Value>) -> Vec<(String, Value)> {
+    let mut flat = vec![];
+
+    for (k, v) in map.into_iter() {
+        match v {
+            Value::Object(o) => for (n, x) in o.into_iter() {
+                if has_value(&x) {
+                    flat.push((format!("{}.{}", k, n), x));
+                }
+            },
+            x => if has_value(&x) {
+                flat.push((k, x));
+            },
+        }
+    }
+
+    flat
+}
+
+fn unflatten(map: &mut Map<String, Value>, path: &str, value: Value) {
+    match path.split_once('.') {
+        Some((parent, field)) => {
+            let entry = map.entry(parent.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+
+            if let Value::Object(o) = entry {
+                o.insert(field.to_string(), value);
+            }
+        },
+        None => {
+            map.insert(path.to_string(), value);
+        },
+    }
+}
+
+pub(crate) fn has_value(v: &Value) -> bool {
+    match v {
+        Value::Null => false,
+        Value::String(s) => ! s.is_empty(),
+        Value::Array(a) => ! a.is_empty(),
+        _ => true,
+    }
+}
+
+fn union(candidates: &[(DataSource, Value)]) -> Option<Value> {
+    if ! candidates.iter().all(|(_, v)|v.is_array()) {
+        return candidates.first().map(|(_, v)|v.to_owned())
+    }
+
+    // Items keep the order the sources list them in, most trusted source first.
+    // Order of the languages tells how prominent they are.
+    let mut items: Vec<Value> = vec![];
+
+    for v in candidates.iter().filter_map(|(_, v)|v.as_array()).flatten() {
+        if ! items.contains(v) {
+            items.push(v.to_owned());
+        }
+    }
+
+    Some(Value::Array(items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Currency;
+    use DataSource::*;
+
+    fn named(exonym: &str, aliases: &[&str]) -> Country {
+        Country {
+            exonym: exonym.into(),
+            aliases: Some(aliases.iter().map(|s|s.to_string()).collect()),
+            ..Country::default()
+        }
+    }
+
+    fn policy(field: &str, sources: Vec<DataSource>, strategy: Strategy) -> MergePolicy {
+        MergePolicy {
+            default: FieldPolicy::default(),
+            fields: BTreeMap::from([(field.to_string(), FieldPolicy { sources, strategy })]),
+        }
+    }
+
+    fn merger() -> Merger {
+        let mut merger = Merger::default();
+        merger.add("FI", WikipediaCountries, named("Finland", &["Suomi"]));
+        merger.add("FI", DatahubCountries, named("Republic of Finland", &["Finnland", "Suomi"]));
+        merger
+    }
+
+    #[test]
+    fn first_wins_by_source_rank() {
+        let countries = merger().countries(&policy("exonym", vec![DatahubCountries],
+            Strategy::FirstWins));
+
+        assert_eq!(countries["FI"].exonym, "Republic of Finland");
+
+        // Sources not ranked keep the order they were added in
+        let countries = merger().countries(&MergePolicy::default());
+
+        assert_eq!(countries["FI"].exonym, "Finland");
+    }
+
+    #[test]
+    fn last_wins_by_source_rank() {
+        let countries = merger().countries(&policy("exonym", vec![DatahubCountries],
+            Strategy::LastWins));
+
+        assert_eq!(countries["FI"].exonym, "Finland");
+    }
+
+    #[test]
+    fn union_combines_lists() {
+        let countries = merger().countries(&policy("aliases", vec![], Strategy::Union));
+
+        assert_eq!(countries["FI"].aliases, Some(vec!["Suomi".into(), "Finnland".into()]));
+    }
+
+    #[test]
+    fn require_agreement_leaves_disagreeing_values_out() {
+        let (countries, conflicts) = merger().merge(&policy("exonym", vec![],
+            Strategy::RequireAgreement));
+
+        assert_eq!(countries["FI"].exonym, "");
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].field, "exonym");
+        assert_eq!(conflicts[0].values.len(), 2);
+        assert!(conflicts[0].error.is_none());
+    }
+
+    #[test]
+    fn invalid_merged_field_is_a_conflict() {
+        let mut merger = merger();
+        merger.add("FI", WikipediaCurrencies, Country {
+            currency: Some(Currency { symbol: Some("€".into()), ..Currency::default() }),
+            ..Country::default()
+        });
+
+        let (countries, conflicts) = merger.merge(&MergePolicy::recommended());
+
+        // Currency has no name, the rest of the country is kept
+        assert!(countries["FI"].currency.is_none());
+        assert_eq!(countries["FI"].name, "");
+        assert!(! countries["FI"].exonym.is_empty());
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].field, "currency");
+        assert_eq!(conflicts[0].values[0].0, WikipediaCurrencies);
+        assert!(conflicts[0].error.is_some());
+    }
+
+    fn currency(name: &str, code: &str, symbol: Option<&str>) -> Country {
+        Country {
+            currency: Some(Currency {
+                name: name.into(), iso_4217: Some(code.into()),
+                symbol: symbol.map(|s|s.to_string()), ..Currency::default()
+            }),
+            ..Country::default()
+        }
+    }
+
+    #[test]
+    fn currency_values_come_from_one_currency() {
+        let mut merger = Merger::default();
+        merger.add("BT", DatahubCurrencies, currency("Indian Rupee", "INR", None));
+        merger.add("BT", DatahubCurrencies, currency("Ngultrum", "BTN", None));
+        merger.add("BT", WikipediaCurrencies, currency("Bhutanese ngultrum", "BTN", Some("Nu.")));
+        merger.add("BT", WikipediaCurrencies, currency("Indian rupee", "INR", Some("₹")));
+
+        let countries = merger.countries(&MergePolicy::recommended());
+        let c = countries["BT"].currency.as_ref().unwrap();
+
+        // Both codes have two sources, datahub ranks first on the code
+        assert_eq!((c.name.as_str(), c.iso_4217.as_deref()), ("Indian Rupee", Some("INR")));
+        assert_eq!(c.symbol.as_deref(), Some("₹"));
+    }
+
+    #[test]
+    fn currency_given_by_most_sources_wins() {
+        let mut merger = Merger::default();
+        merger.add("CH", DatahubCurrencies, currency("WIR Euro", "CHE", None));
+        merger.add("CH", DatahubCurrencies, currency("Swiss Franc", "CHF", None));
+        merger.add("CH", WikipediaCurrencies, currency("Swiss franc", "CHF", Some("Fr.")));
+        merger.add("CH", WikipediaCurrencies, Country {
+            currency: Some(Currency { name: "Euro".into(), symbol: Some("€".into()),
+                ..Currency::default() }),
+            ..Country::default()
+        });
+
+        let countries = merger.countries(&MergePolicy::recommended());
+        let c = countries["CH"].currency.as_ref().unwrap();
+
+        assert_eq!((c.name.as_str(), c.iso_4217.as_deref()), ("Swiss Franc", Some("CHF")));
+        assert_eq!(c.symbol.as_deref(), Some("Fr."));
+    }
+
+    #[test]
+    fn time_values_come_from_one_zone() {
+        let time = |zone: &str, dst: Option<&str>|Country {
+            time: Some(crate::Time { zone: zone.into(), dst: dst.map(|s|s.to_string()) }),
+            ..Country::default()
+        };
+
+        let mut merger = Merger::default();
+        merger.add("US", WikipediaCallingCodes, time("UTC−05:00", Some("UTC−04:00")));
+        merger.add("US", WikipediaCallingCodes, time("UTC−10:00", None));
+
+        let countries = merger.countries(&MergePolicy::recommended());
+        let t = countries["US"].time.as_ref().unwrap();
+
+        assert_eq!((t.zone.as_str(), t.dst.as_deref()), ("UTC−10:00", None));
+    }
+
+    #[test]
+    fn policy_of_parent_covers_nested_fields() {
+        let policy = MergePolicy::recommended();
+
+        assert_eq!(policy.field("currency.symbol").sources, vec![WikipediaCurrencies]);
+        assert_eq!(policy.field("currency.name").sources[0], DatahubCurrencies);
+        assert_eq!(policy.field("capital").strategy, Strategy::FirstWins);
+    }
+}
@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::DataSource;
+use crate::drift::Fingerprint;
+use crate::error::{Error, Result};
+
+/// Build metadata written next to the output file, `out.json` gets `out.meta.json`
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct BuildMeta {
+    pub sources: BTreeMap<DataSource, SourceMeta>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct SourceMeta {
+    pub url: String,
+    /// Missing if the source has never been read successfully
+    pub fingerprint: Option<Fingerprint>,
+    /// Source failed and its values were carried over from an earlier build
+    #[serde(default)]
+    pub stale: bool,
+}
+
+impl BuildMeta {
+    pub fn path_for(out: &Path) -> PathBuf {
+        let stem = out.file_stem().unwrap_or_default().to_string_lossy();
+
+        out.with_file_name(format!("{}.meta.json", stem))
+    }
+
+    /// Read metadata of a previous build, `None` if there's no previous build
+    pub fn read(path: &Path) -> Result<Option<Self>> {
+        let json = match read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::File { path: path.to_owned(), source: e }),
+        };
+
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| Error::Json { url: path.display().to_string(), source: e })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::Json { url: path.display().to_string(), source: e })?;
+
+        write(path, json.as_bytes())
+            .map_err(|e| Error::File { path: path.to_owned(), source: e })
+    }
+
+    pub fn fingerprints(&self) -> BTreeMap<DataSource, Fingerprint> {
+        self.sources.iter()
+            .filter_map(|(s, m)|m.fingerprint.to_owned().map(|f|(*s, f)))
+            .collect()
+    }
+
+    /// Flag the source as failed, whatever it had in the previous build is what
+    /// the output has now.
+    pub fn mark_stale(&mut self, source: DataSource) {
+        let meta = self.sources.entry(source).or_insert_with(|| SourceMeta {
+            url: source.url().to_string(), ..SourceMeta::default()
+        });
+
+        meta.stale = true;
+    }
+
+    pub fn from_fingerprints(fingerprints: &BTreeMap<DataSource, Fingerprint>) -> Self {
+        let sources = fingerprints.iter()
+            .map(|(s, f)|(*s, SourceMeta {
+                url: s.url().to_string(), fingerprint: Some(f.to_owned()), stale: false
+            }))
+            .collect();
+
+        Self { sources }
+    }
+}
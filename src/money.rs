@@ -0,0 +1,429 @@
+use std::collections::BTreeMap;
+use std::fs::read_to_string;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+
+use crate::{Country, Currency};
+use crate::currency::iso_4217;
+use crate::error::{Error, Result};
+
+const BUNDLED: &str = include_str!("../data/number_formats.json");
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolPosition {
+    Before,
+    After,
+}
+
+/// How amounts are written in a locale
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct NumberFormat {
+    pub decimal: String,
+    pub group: String,
+    /// Sizes of digit groups from the right, last one repeats. `[3, 2]` gives
+    /// `1,00,000`.
+    pub grouping: Vec<usize>,
+    pub symbol_position: SymbolPosition,
+    /// Space between the symbol and the amount
+    pub symbol_space: bool,
+}
+
+/// Number formats by locale (`de-CH`) or language (`de`), `root` is used when
+/// neither is found.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct FormatRegistry {
+    #[serde(flatten)]
+    pub formats: BTreeMap<String, NumberFormat>,
+}
+
+impl FormatRegistry {
+    /// Formats shipped with the crate
+    pub fn bundled() -> Self {
+        serde_json::from_str(BUNDLED).expect("Bundled number formats are not valid JSON")
+    }
+
+    /// Read formats of the same shape as `data/number_formats.json` from a file
+    pub fn read(path: &Path) -> Result<Self> {
+        let json = read_to_string(path)
+            .map_err(|e| Error::File { path: path.to_owned(), source: e })?;
+
+        serde_json::from_str(&json)
+            .map_err(|e| Error::Json { url: path.display().to_string(), source: e })
+    }
+
+    pub fn for_locale(&self, locale: &str) -> NumberFormat {
+        let language = locale.split('-').next().unwrap_or_default();
+
+        self.formats.get(locale)
+            .or_else(||self.formats.get(language))
+            .or_else(||self.formats.get("root"))
+            .cloned()
+            .unwrap_or(NumberFormat {
+                decimal: ".".into(), group: ",".into(), grouping: vec![3],
+                symbol_position: SymbolPosition::Before, symbol_space: false
+            })
+    }
+}
+
+impl Default for FormatRegistry {
+    fn default() -> Self {
+        Self::bundled()
+    }
+}
+
+/// Formats and reads amounts of a currency as written in a locale. Amounts are
+/// handled in minor units (cents) to keep them exact.
+#[derive(Debug, Clone)]
+pub struct MoneyFormat {
+    pub currency: Currency,
+    pub format: NumberFormat,
+}
+
+impl MoneyFormat {
+    pub fn new(currency: &Currency, locale: &str, registry: &FormatRegistry) -> Self {
+        Self { currency: currency.to_owned(), format: registry.for_locale(locale) }
+    }
+
+    /// Currency of the country as written in its default locale
+    pub fn for_country(country: &Country, registry: &FormatRegistry) -> Option<Self> {
+        let currency = country.currency.as_ref()?;
+        let locale = country.default_locale().unwrap_or("root");
+
+        Some(Self::new(currency, locale, registry))
+    }
+
+    /// Decimals of the minor unit. ISO 4217 minor unit of the currency if we have
+    /// it, else the one of the bundled ISO 4217 list, else from the fractions in
+    /// unit of currencies not on the list.
+    pub fn decimals(&self) -> Result<u32> {
+        if let Some(m) = self.currency.minor_unit {
+            return Ok(m as u32)
+        }
+
+        let unknown = || Error::UnknownMinorUnit {
+            currency: self.currency.iso_4217.to_owned().unwrap_or(self.currency.name.to_owned())
+        };
+
+        if let Some(m) = self.currency.iso_4217.as_ref().and_then(|c|iso_4217().get(c)) {
+            return m.minor_unit.map(|m|m as u32).ok_or_else(unknown)
+        }
+
+        match self.currency.fractions_in_unit {
+            Some(f) if f > 1 && 10_i32.checked_pow(f.ilog10()) == Some(f) => Ok(f.ilog10()),
+            Some(1) => Ok(0),
+            _ => Err(unknown()),
+        }
+    }
+
+    /// Symbol to write the amounts with, the currency code if there's no symbol
+    pub fn symbol(&self) -> &str {
+        match (&self.currency.symbol, &self.currency.iso_4217) {
+            (Some(s), _) => s,
+            (None, Some(c)) => c,
+            (None, None) => "",
+        }
+    }
+
+    /// Amount rounded to the minor unit, half away from zero. Rounding is done on
+    /// the decimal digits of the amount, `1.005` euros is 101 cents.
+    pub fn to_minor(&self, amount: f64) -> Result<i64> {
+        let invalid = |m: &str| Error::InvalidAmount { input: amount.to_string(), message: m.to_string() };
+
+        if ! amount.is_finite() {
+            return Err(invalid("not a finite number"))
+        }
+
+        let decimals = self.decimals()? as usize;
+
+        // Shortest digits reading back as the same amount, never in exponent form
+        let s = amount.abs().to_string();
+        let (whole, fraction) = s.split_once('.').unwrap_or((&s, ""));
+        let kept = format!("{}{:0<width$}", whole, &fraction[..fraction.len().min(decimals)],
+            width = decimals);
+        let up = fraction.chars().nth(decimals).is_some_and(|c|c >= '5');
+
+        let minor = kept.parse::<i64>().ok()
+            .and_then(|m|m.checked_add(up as i64))
+            .ok_or(invalid("too large"))?;
+
+        match amount < 0.0 {
+            true => Ok(-minor),
+            false => Ok(minor),
+        }
+    }
+
+    pub fn format(&self, amount: f64) -> Result<String> {
+        self.format_minor(self.to_minor(amount)?)
+    }
+
+    /// Amount given in minor units, `format_minor(123456)` of euros in `fi-FI` is
+    /// `1 234,56 €`
+    pub fn format_minor(&self, minor: i64) -> Result<String> {
+        let decimals = self.decimals()?;
+        let digits = minor.unsigned_abs().to_string();
+        let digits = format!("{:0>width$}", digits, width = decimals as usize + 1);
+        let (whole, fraction) = digits.split_at(digits.len() - decimals as usize);
+
+        let mut number = self.group(whole);
+
+        if decimals > 0 {
+            number.push_str(&self.format.decimal);
+            number.push_str(fraction);
+        }
+
+        let space = match self.format.symbol_space {
+            true => "\u{a0}",
+            false => "",
+        };
+
+        let sign = match minor < 0 {
+            true => "-",
+            false => "",
+        };
+
+        match self.format.symbol_position {
+            SymbolPosition::Before => Ok(format!("{}{}{}{}", sign, self.symbol(), space, number)),
+            SymbolPosition::After => Ok(format!("{}{}{}{}", sign, number, space, self.symbol())),
+        }
+    }
+
+    /// Read amount written in the locale into minor units. Symbols and the currency
+    /// code are ignored, the sign goes at the start or the end and group separators
+    /// only where the grouping of the locale puts them. Decimals beyond the minor
+    /// unit are refused.
+    pub fn parse(&self, input: &str) -> Result<i64> {
+        let invalid = |m: &str| Error::InvalidAmount { input: input.to_string(), message: m.to_string() };
+
+        let mut s = input.to_string();
+
+        for x in self.currency.symbols.iter().flatten().map(|x|x.symbol.as_str())
+        .chain(self.currency.symbol.as_deref())
+        .chain(self.currency.iso_4217.as_deref()) {
+            s = s.replace(x, "");
+        }
+
+        let s = s.trim();
+        let (negative, s) = match s.strip_prefix(['-', '−']).or(s.strip_suffix(['-', '−'])) {
+            Some(x) => (true, x.trim()),
+            None => (false, s),
+        };
+
+        if s.contains(['-', '−']) {
+            return Err(invalid("sign goes at the start or the end"))
+        }
+
+        let (whole, fraction) = match s.split_once(self.format.decimal.as_str()) {
+            Some((w, f)) => (w, f),
+            None => (s, ""),
+        };
+
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(invalid("no digits"))
+        }
+
+        let whole = self.ungroup(whole).ok_or_else(||invalid("misplaced group separator"))?;
+
+        if ! whole.chars().chain(fraction.chars()).all(|c|c.is_ascii_digit()) {
+            return Err(invalid("not a number"))
+        }
+
+        let decimals = self.decimals()? as usize;
+
+        if fraction.len() > decimals {
+            return Err(invalid(&format!("more than {} decimals", decimals)))
+        }
+
+        let digits = format!("{}{:0<width$}", whole, fraction, width = decimals);
+        let minor = match digits.is_empty() {
+            true => 0,
+            false => digits.parse::<i64>().map_err(|e| invalid(&e.to_string()))?,
+        };
+
+        match negative {
+            true => Ok(-minor),
+            false => Ok(minor),
+        }
+    }
+
+    /// Digits of the whole part without group separators, `None` if the groups
+    /// are not of the sizes the grouping gives. Any whitespace stands in for a
+    /// space separator.
+    fn ungroup(&self, whole: &str) -> Option<String> {
+        let groups = match self.format.group.trim().is_empty() {
+            true => whole.split(char::is_whitespace).collect::<Vec<_>>(),
+            false => whole.split(self.format.group.as_str()).collect::<Vec<_>>(),
+        };
+
+        let (first, rest) = groups.split_first()?;
+        let mut sizes = self.format.grouping.iter();
+        let mut size = sizes.next().copied().unwrap_or(0);
+
+        for g in rest.iter().rev() {
+            if g.len() != size {
+                return None
+            }
+
+            if let Some(s) = sizes.next() {
+                size = *s;
+            }
+        }
+
+        // Leading group may be shorter than the rest
+        if ! rest.is_empty() && (first.is_empty() || first.len() > size) {
+            return None
+        }
+
+        Some(groups.concat())
+    }
+
+    fn group(&self, whole: &str) -> String {
+        let mut groups = vec![];
+        let mut rest = whole;
+        let mut sizes = self.format.grouping.iter();
+        let mut size = sizes.next().copied().unwrap_or(0);
+
+        while size > 0 && rest.len() > size {
+            let (head, tail) = rest.split_at(rest.len() - size);
+
+            groups.push(tail);
+            rest = head;
+
+            if let Some(s) = sizes.next() {
+                size = *s;
+            }
+        }
+
+        groups.push(rest);
+        groups.reverse();
+        groups.join(&self.format.group)
+    }
+}
+
+impl Country {
+    /// Format of the country's currency in its default locale
+    pub fn money_format(&self, registry: &FormatRegistry) -> Option<MoneyFormat> {
+        MoneyFormat::for_country(self, registry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn money(code: &str, symbol: Option<&str>, minor_unit: Option<u8>, locale: &str) -> MoneyFormat {
+        let currency = Currency {
+            name: code.into(), iso_4217: Some(code.into()), minor_unit,
+            symbol: symbol.map(|s|s.to_string()), ..Currency::default()
+        };
+
+        MoneyFormat::new(&currency, locale, &FormatRegistry::bundled())
+    }
+
+    #[test]
+    fn rounds_on_decimal_digits() {
+        let eur = money("EUR", Some("€"), Some(2), "fi-FI");
+
+        assert_eq!(eur.to_minor(1.005).unwrap(), 101);
+        assert_eq!(eur.to_minor(1.004).unwrap(), 100);
+        assert_eq!(eur.to_minor(-2.675).unwrap(), -268);
+        assert_eq!(eur.to_minor(0.1 + 0.2).unwrap(), 30);
+        assert!(eur.to_minor(f64::NAN).is_err());
+        assert!(eur.to_minor(1e30).is_err());
+    }
+
+    #[test]
+    fn formats_as_the_locale_does() {
+        let eur = money("EUR", Some("€"), Some(2), "fi-FI");
+
+        assert_eq!(eur.format(1.005).unwrap(), "1,01\u{a0}€");
+        assert_eq!(eur.format_minor(-123456789).unwrap(), "-1\u{a0}234\u{a0}567,89\u{a0}€");
+
+        let inr = money("INR", Some("₹"), Some(2), "en-IN");
+
+        assert_eq!(inr.format(12345678.9).unwrap(), "₹1,23,45,678.90");
+    }
+
+    #[test]
+    fn takes_minor_units_missing_from_the_bundled_list() {
+        let jpy = money("JPY", Some("¥"), None, "ja-JP");
+
+        assert_eq!(jpy.decimals().unwrap(), 0);
+        assert_eq!(jpy.format(1234.5).unwrap(), "¥1,235");
+
+        let kwd = money("KWD", None, None, "en");
+
+        assert_eq!(kwd.format(1.0005).unwrap(), "KWD1.001");
+    }
+
+    #[test]
+    fn refuses_amounts_of_unknown_minor_units() {
+        let xau = money("XAU", None, None, "en");
+
+        assert!(matches!(xau.decimals(), Err(Error::UnknownMinorUnit { ref currency })
+            if currency == "XAU"));
+        assert!(xau.format(1.0).is_err());
+        assert!(money("ABC", None, None, "en").parse("1").is_err());
+    }
+
+    #[test]
+    fn parses_amounts_into_minor_units() {
+        let eur = money("EUR", Some("€"), Some(2), "fi-FI");
+
+        assert_eq!(eur.parse("1\u{a0}234,56\u{a0}€").unwrap(), 123456);
+        assert_eq!(eur.parse("-12,5 EUR").unwrap(), -1250);
+        assert_eq!(eur.parse(",5").unwrap(), 50);
+        assert!(eur.parse("1,234").is_err());
+        assert!(eur.parse("€").is_err());
+        assert!(eur.parse("1,2x").is_err());
+
+        let jpy = money("JPY", Some("¥"), None, "ja-JP");
+
+        assert_eq!(jpy.parse("¥1,235").unwrap(), 1235);
+    }
+
+    #[test]
+    fn sign_goes_at_either_end() {
+        let usd = money("USD", Some("$"), Some(2), "en-US");
+
+        assert_eq!(usd.parse("-5").unwrap(), -500);
+        assert_eq!(usd.parse("5-").unwrap(), -500);
+        assert_eq!(usd.parse("−$1,234.50").unwrap(), -123450);
+        assert_eq!(usd.parse("$ 5 -").unwrap(), -500);
+        assert!(usd.parse("1-2").is_err());
+        assert!(usd.parse("--5").is_err());
+        assert!(usd.parse("-5-").is_err());
+        assert!(usd.parse("-").is_err());
+    }
+
+    #[test]
+    fn group_separators_go_where_grouping_puts_them() {
+        let eur = money("EUR", Some("€"), Some(2), "de-DE");
+
+        assert_eq!(eur.parse("1.234.567,89").unwrap(), 123456789);
+        assert_eq!(eur.parse("12,5").unwrap(), 1250);
+        assert!(eur.parse("12.5").is_err());
+        assert!(eur.parse("1.23").is_err());
+        assert!(eur.parse("1234.567").is_err());
+        assert!(eur.parse(".123").is_err());
+        assert!(eur.parse("1..234").is_err());
+        assert!(eur.parse("1,234.5").is_err());
+
+        let usd = money("USD", Some("$"), Some(2), "en-US");
+
+        assert_eq!(usd.parse("1234567.5").unwrap(), 123456750);
+        assert!(usd.parse("1,2345").is_err());
+        assert!(usd.parse("1 234").is_err());
+
+        // Any whitespace stands in for a space separator
+        let fi = money("EUR", Some("€"), Some(2), "fi-FI");
+
+        assert_eq!(fi.parse("1 234 567,5").unwrap(), 123456750);
+        assert!(fi.parse("12 34").is_err());
+
+        let inr = money("INR", Some("₹"), Some(2), "hi-IN");
+
+        assert_eq!(inr.parse("₹1,00,000").unwrap(), 10000000);
+        assert!(inr.parse("₹100,000").is_err());
+    }
+}
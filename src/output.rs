@@ -0,0 +1,332 @@
+//! Writers of the built countries in formats other tools like. JSON, NDJSON,
+//! YAML and TOML keep every field and can be read back, CSV is flattened into a
+//! row per country for spreadsheets.
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::str::FromStr;
+use serde::Serialize;
+
+use crate::{backfill_alpha2, Country};
+use crate::error::{Error, Result};
+
+/// Separator of values in list columns of CSV
+pub const CSV_LIST_SEPARATOR: &str = "; ";
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Json,
+    /// A JSON object per line, one for each country
+    Ndjson,
+    Csv,
+    Yaml,
+    Toml,
+}
+
+impl Format {
+    pub const ALL: [Self; 5] = [Self::Json, Self::Ndjson, Self::Csv, Self::Yaml, Self::Toml];
+
+    /// Format of the file extension, `None` if it's not one of ours
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_string_lossy().to_lowercase();
+
+        match ext.as_str() {
+            "yml" => Some(Self::Yaml),
+            "jsonl" => Some(Self::Ndjson),
+            x => x.parse().ok(),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Ndjson => "ndjson",
+            Self::Csv => "csv",
+            Self::Yaml => "yaml",
+            Self::Toml => "toml",
+        }
+    }
+
+    pub fn writer(&self) -> Box<dyn CountryWriter> {
+        match self {
+            Self::Json => Box::new(JsonWriter),
+            Self::Ndjson => Box::new(NdjsonWriter),
+            Self::Csv => Box::new(CsvWriter),
+            Self::Yaml => Box::new(YamlWriter),
+            Self::Toml => Box::new(TomlWriter),
+        }
+    }
+
+    /// Countries written in the format, CSV can't be read back
+    pub fn read(&self, source: &str, s: &str) -> Result<BTreeMap<String, Country>> {
+        let input = |m: String| Error::Input {
+            url: source.to_string(), format: self.to_string(), message: m
+        };
+
+        let mut countries: BTreeMap<String, Country> = match self {
+            Self::Json => serde_json::from_str(s)
+                .map_err(|e| Error::Json { url: source.to_string(), source: e }),
+            Self::Ndjson => s.lines()
+                .filter(|l|! l.trim().is_empty())
+                .map(|l|serde_json::from_str::<Country>(l)
+                    .map(|c|(c.iso_3166_1_a2.to_owned(), c))
+                    .map_err(|e| Error::Json { url: source.to_string(), source: e }))
+                .collect(),
+            Self::Csv => Err(input("flattened rows can't be read back into countries".into())),
+            Self::Yaml => serde_yaml::from_str(s).map_err(|e| input(e.to_string())),
+            Self::Toml => toml::from_str(s).map_err(|e| input(e.to_string())),
+        }?;
+
+        backfill_alpha2(&mut countries);
+
+        Ok(countries)
+    }
+
+    /// Countries of a previous build written in the format, `None` if there's no
+    /// previous build
+    pub fn read_previous(&self, path: &Path) -> Result<Option<BTreeMap<String, Country>>> {
+        let s = match read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(Error::File { path: path.to_owned(), source: e }),
+        };
+
+        self.read(&path.display().to_string(), &s).map(Some)
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(Self::Json),
+            "ndjson" => Ok(Self::Ndjson),
+            "csv" => Ok(Self::Csv),
+            "yaml" => Ok(Self::Yaml),
+            "toml" => Ok(Self::Toml),
+            x => Err(format!("Unknown format {}, expected json, ndjson, csv, yaml or toml", x)),
+        }
+    }
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+/// Turns the countries into text of some format. Implement it to plug in formats
+/// of your own.
+pub trait CountryWriter {
+    fn write(&self, countries: &BTreeMap<String, Country>) -> Result<String>;
+}
+
+fn output_error(format: Format, e: impl ToString) -> Error {
+    Error::Output { format: format.to_string(), message: e.to_string() }
+}
+
+/// Pretty printed object of countries by their alpha-2 codes
+pub struct JsonWriter;
+
+impl CountryWriter for JsonWriter {
+    fn write(&self, countries: &BTreeMap<String, Country>) -> Result<String> {
+        serde_json::to_string_pretty(countries).map_err(|e| output_error(Format::Json, e))
+    }
+}
+
+pub struct NdjsonWriter;
+
+impl CountryWriter for NdjsonWriter {
+    fn write(&self, countries: &BTreeMap<String, Country>) -> Result<String> {
+        let mut s = String::new();
+
+        for c in countries.values() {
+            s.push_str(&serde_json::to_string(c).map_err(|e| output_error(Format::Ndjson, e))?);
+            s.push('\n');
+        }
+
+        Ok(s)
+    }
+}
+
+pub struct YamlWriter;
+
+impl CountryWriter for YamlWriter {
+    fn write(&self, countries: &BTreeMap<String, Country>) -> Result<String> {
+        serde_yaml::to_string(countries).map_err(|e| output_error(Format::Yaml, e))
+    }
+}
+
+/// Table of each country, empty values are left out
+pub struct TomlWriter;
+
+impl CountryWriter for TomlWriter {
+    fn write(&self, countries: &BTreeMap<String, Country>) -> Result<String> {
+        toml::to_string_pretty(countries).map_err(|e| output_error(Format::Toml, e))
+    }
+}
+
+/// A row per country. Currency and time are split into columns of their own,
+/// lists are joined with `CSV_LIST_SEPARATOR`. Names stand in for endonyms,
+/// languages and historical currencies, codes for scripts.
+pub struct CsvWriter;
+
+#[derive(Serialize)]
+struct CsvRow<'a> {
+    iso_3166_1_a2: &'a str,
+    exonym: &'a str,
+    name: &'a str,
+    aliases: String,
+    capital: Option<&'a str>,
+    capital_endonyms: String,
+    iso_3166_1_a3: Option<&'a str>,
+    iso_3166_1_numeric: Option<u16>,
+    tld: Option<&'a str>,
+    flag: Option<&'a str>,
+    currency_name: Option<&'a str>,
+    currency_iso_4217: Option<&'a str>,
+    currency_numeric_code: Option<u16>,
+    currency_minor_unit: Option<u8>,
+    currency_symbol: Option<&'a str>,
+    currency_fraction: Option<&'a str>,
+    currency_fractions_in_unit: Option<i32>,
+    historical_currencies: String,
+    time_zone: Option<&'a str>,
+    time_dst: Option<&'a str>,
+    calling_code: Option<&'a str>,
+    endonyms: String,
+    languages: String,
+    scripts: String,
+    locales: String,
+}
+
+impl<'a> CsvRow<'a> {
+    fn new(c: &'a Country) -> Self {
+        let currency = c.currency.as_ref();
+        let join = |v: Vec<&str>|v.join(CSV_LIST_SEPARATOR);
+
+        Self {
+            iso_3166_1_a2: &c.iso_3166_1_a2,
+            exonym: &c.exonym,
+            name: &c.name,
+            aliases: join(c.aliases.iter().flatten().map(|s|s.as_str()).collect()),
+            capital: c.capital.as_deref(),
+            capital_endonyms: join(c.capital_endonyms.iter().flatten()
+                .map(|e|e.name.as_str()).collect()),
+            iso_3166_1_a3: c.iso_3166_1_a3.as_ref().map(|s|s.as_str()),
+            iso_3166_1_numeric: c.iso_3166_1_numeric,
+            tld: c.tld.as_ref().map(|s|s.as_str()),
+            flag: c.flag.as_ref().map(|s|s.as_str()),
+            currency_name: currency.map(|x|x.name.as_str()),
+            currency_iso_4217: currency.and_then(|x|x.iso_4217.as_deref()),
+            currency_numeric_code: currency.and_then(|x|x.numeric_code),
+            currency_minor_unit: currency.and_then(|x|x.minor_unit),
+            currency_symbol: currency.and_then(|x|x.symbol.as_deref()),
+            currency_fraction: currency.and_then(|x|x.fraction.as_deref()),
+            currency_fractions_in_unit: currency.and_then(|x|x.fractions_in_unit),
+            historical_currencies: join(c.historical_currencies.iter().flatten()
+                .map(|x|x.iso_4217.as_deref().unwrap_or(&x.name)).collect()),
+            time_zone: c.time.as_ref().map(|t|t.zone.as_str()),
+            time_dst: c.time.as_ref().and_then(|t|t.dst.as_deref()),
+            calling_code: c.calling_code.as_ref().map(|s|s.as_str()),
+            endonyms: join(c.endonyms.iter().flatten().map(|e|e.name.as_str()).collect()),
+            languages: join(c.languages.iter().flatten().map(|l|l.name.as_str()).collect()),
+            scripts: join(c.scripts.iter().flatten().map(|s|s.code.as_str()).collect()),
+            locales: join(c.locales.iter().flatten().map(|s|s.as_str()).collect()),
+        }
+    }
+}
+
+impl CountryWriter for CsvWriter {
+    fn write(&self, countries: &BTreeMap<String, Country>) -> Result<String> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+
+        for c in countries.values() {
+            writer.serialize(CsvRow::new(c)).map_err(|e| output_error(Format::Csv, e))?;
+        }
+
+        let bytes = writer.into_inner().map_err(|e| output_error(Format::Csv, e))?;
+
+        String::from_utf8(bytes).map_err(|e| output_error(Format::Csv, e))
+    }
+}
+
+/// Countries written in the format, keyed by `String` or the code enums
+pub fn to_string<K: Display>(format: Format, countries: &BTreeMap<K, Country>) -> Result<String> {
+    let countries = countries.iter()
+        .map(|(k, c)|(k.to_string(), c.to_owned()))
+        .collect();
+
+    format.writer().write(&countries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CountryCode;
+
+    fn countries() -> BTreeMap<String, Country> {
+        let mut all = Format::Json.read("out.json", include_str!("../out.json")).unwrap();
+        all.retain(|k, _|["FI", "IN", "JP", "US", "ZW"].contains(&k.as_str()));
+        all
+    }
+
+    #[test]
+    fn formats_read_back_what_they_wrote() {
+        let countries = countries();
+        let expected = serde_json::to_value(&countries).unwrap();
+
+        for f in [Format::Json, Format::Ndjson, Format::Yaml, Format::Toml] {
+            let s = to_string(f, &countries).unwrap();
+            let read = f.read("test", &s).unwrap();
+
+            assert_eq!(serde_json::to_value(&read).unwrap(), expected, "{} differs", f);
+        }
+    }
+
+    #[test]
+    fn csv_has_a_row_per_country() {
+        let s = to_string(Format::Csv, &countries()).unwrap();
+        let mut reader = csv::Reader::from_reader(s.as_bytes());
+        let headers = reader.headers().unwrap().to_owned();
+        let rows = reader.records().collect::<std::result::Result<Vec<_>, _>>().unwrap();
+
+        assert_eq!(&headers[0], "iso_3166_1_a2");
+        assert_eq!(headers.len(), 25);
+        assert_eq!(rows.len(), 5);
+        assert_eq!(&rows[0][0], "FI");
+        assert_eq!(&rows[0][headers.iter().position(|h|h == "currency_iso_4217").unwrap()], "EUR");
+        assert!(Format::Csv.read("test", &s).is_err());
+    }
+
+    #[test]
+    fn takes_countries_keyed_by_code_enums() {
+        let countries = countries().into_iter()
+            .map(|(k, c)|(k.parse::<CountryCode>().unwrap(), c))
+            .collect::<BTreeMap<_, _>>();
+
+        let read = Format::Json.read("test", &to_string(Format::Json, &countries).unwrap()).unwrap();
+
+        assert_eq!(read.keys().collect::<Vec<_>>(), vec!["FI", "IN", "JP", "US", "ZW"]);
+    }
+
+    #[test]
+    fn builds_without_alpha2_codes_get_them_from_keys() {
+        let read = Format::Json.read("test", r#"{"FI": {"exonym": "Finland", "name": "Finland"}}"#)
+            .unwrap();
+
+        assert_eq!(read["FI"].iso_3166_1_a2, "FI");
+        assert!(to_string(Format::Csv, &read).unwrap().lines().nth(1).unwrap().starts_with("FI,"));
+    }
+
+    #[test]
+    fn formats_from_extensions() {
+        assert_eq!(Format::from_path(Path::new("out.YML")), Some(Format::Yaml));
+        assert_eq!(Format::from_path(Path::new("out.jsonl")), Some(Format::Ndjson));
+        assert_eq!(Format::from_path(Path::new("out.txt")), None);
+    }
+}
@@ -1,5 +1,8 @@
-use serde::Deserialize;
-use anyhow::{Result, bail, anyhow};
+use std::str::FromStr;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::error::{Error, Result};
 
 #[derive(Deserialize, Default, Debug)]
 pub struct DatahubCountry {
@@ -12,19 +15,13 @@ pub struct DatahubCountry {
 impl DatahubCountry {
     pub fn pull(url: &str) -> Result<Vec<Self>> {
         println!("Pull ISO 3166-1 country data from {}...", url);
-    
-        let resp = match reqwest::blocking::get(url) {
-            Ok(r) => r,
-            Err(e) => bail!("Failed to read link ({}): {}", url, e),
-        };
-    
-        let html = match resp.text() {
-            Ok(s) => s,
-            Err(e) => bail!("Failed to read http response from host: {}", e),
-        };
-        
-        serde_json::from_str::<Vec<Self>>(&html)
-            .map_err(|e| anyhow!("Failed to read countries as JSON: {}", e))
+
+        Self::from_json(url, &super::fetch(url)?)
+    }
+
+    pub fn from_json(url: &str, json: &str) -> Result<Vec<Self>> {
+        serde_json::from_str::<Vec<Self>>(json)
+            .map_err(|e| Error::Json { url: url.to_string(), source: e })
     }
 }
 
@@ -35,24 +32,102 @@ pub struct DatahubCurrency {
     #[serde(alias = "AlphabeticCode")]
     pub iso_4217: Option<String>,
     #[serde(alias = "Currency")]
-    pub name: Option<String>
+    pub name: Option<String>,
+    #[serde(alias = "NumericCode", default, deserialize_with = "optional_number")]
+    pub numeric_code: Option<u16>,
+    #[serde(alias = "MinorUnit", default, deserialize_with = "optional_number")]
+    pub minor_unit: Option<u8>,
+    #[serde(alias = "WithdrawalDate", default)]
+    pub withdrawal_date: Option<String>,
 }
 
 impl DatahubCurrency {
     pub fn pull(url: &str) -> Result<Vec<Self>> {
         println!("Pull currency data from {}...", url);
-    
-        let resp = match reqwest::blocking::get(url) {
-            Ok(r) => r,
-            Err(e) => bail!("Failed to read link ({}): {}", url, e),
-        };
-    
-        let html = match resp.text() {
-            Ok(s) => s,
-            Err(e) => bail!("Failed to read http response from host: {}", e),
-        };
-        
-        serde_json::from_str::<Vec<Self>>(&html)
-            .map_err(|e| anyhow!("Failed to read currencies as JSON: {}", e))
+
+        Self::from_json(url, &super::fetch(url)?)
+    }
+
+    pub fn from_json(url: &str, json: &str) -> Result<Vec<Self>> {
+        serde_json::from_str::<Vec<Self>>(json)
+            .map_err(|e| Error::Json { url: url.to_string(), source: e })
+    }
+
+    /// Withdrawal date as year and month. Periods (`1989 to 1990`) are read as
+    /// their end.
+    pub fn withdrawn(&self) -> Option<String> {
+        self.withdrawal_date.as_ref()?
+            .split_whitespace()
+            .rev()
+            .find(|s|s.starts_with(|c: char|c.is_ascii_digit()))
+            .map(|s|s.to_string())
+    }
+}
+
+/// Numbers come as floats (`978.0`), strings (`"2"`) or placeholders (`"N.A."`)
+fn optional_number<'de, D, T>(d: D) -> std::result::Result<Option<T>, D::Error>
+where D: Deserializer<'de>, T: FromStr {
+    let s = match Value::deserialize(d)? {
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if f.fract() == 0.0 => (f as u64).to_string(),
+            _ => n.to_string(),
+        },
+        Value::String(s) => s.trim().to_string(),
+        _ => return Ok(None),
+    };
+
+    Ok(s.parse::<T>().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Country;
+
+    const CODES: &str = r#"[
+        {"Entity": "FINLAND", "Currency": "Euro", "AlphabeticCode": "EUR", "NumericCode": 978.0,
+            "MinorUnit": "2", "WithdrawalDate": null},
+        {"Entity": "FINLAND", "Currency": "Markka", "AlphabeticCode": "FIM", "NumericCode": 246.0,
+            "MinorUnit": null, "WithdrawalDate": "2002-03"},
+        {"Entity": "ANTARCTICA", "Currency": "No universal currency", "AlphabeticCode": null,
+            "NumericCode": null, "MinorUnit": null, "WithdrawalDate": null},
+        {"Entity": "BOLIVIA", "Currency": "Bolivian Peso", "AlphabeticCode": "BOP",
+            "NumericCode": "068", "MinorUnit": "N.A.", "WithdrawalDate": "1987-02"},
+        {"Entity": "NICARAGUA", "Currency": "Cordoba", "AlphabeticCode": "NIC", "NumericCode": 558.0,
+            "MinorUnit": null, "WithdrawalDate": "1989 to 1990"}
+    ]"#;
+
+    #[test]
+    fn reads_codes_and_withdrawal_dates() {
+        let v = DatahubCurrency::from_json("test", CODES).unwrap();
+
+        assert_eq!(v.len(), 5);
+        assert_eq!((v[0].numeric_code, v[0].minor_unit), (Some(978), Some(2)));
+        assert_eq!(v[0].withdrawn(), None);
+        assert_eq!(v[1].withdrawn().as_deref(), Some("2002-03"));
+        assert_eq!((v[3].numeric_code, v[3].minor_unit), (Some(68), None));
+        // Periods are read as their end
+        assert_eq!(v[4].withdrawn().as_deref(), Some("1990"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn withdrawn_currencies_are_historical() {
+        let mut v = DatahubCurrency::from_json("test", CODES).unwrap().into_iter()
+            .map(Country::from_currency_list);
+
+        let euro = v.next().unwrap().unwrap();
+
+        assert_eq!(euro.currency.unwrap().iso_4217.as_deref(), Some("EUR"));
+        assert!(euro.historical_currencies.is_none());
+
+        let markka = v.next().unwrap().unwrap();
+        let historical = markka.historical_currencies.unwrap();
+
+        assert!(markka.currency.is_none());
+        assert_eq!(historical[0].iso_4217.as_deref(), Some("FIM"));
+        assert_eq!(historical[0].withdrawn.as_deref(), Some("2002-03"));
+
+        // Entities without a currency give nothing
+        assert!(v.next().unwrap().is_none());
+    }
+}
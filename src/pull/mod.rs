@@ -1 +1,9 @@
 pub mod datahub;
+
+use crate::error::Result;
+use crate::http::http_get;
+
+/// Read the JSON document behind the url as is
+pub fn fetch(url: &str) -> Result<String> {
+    http_get(url)
+}
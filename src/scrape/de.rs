@@ -0,0 +1,349 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use serde::de::{DeserializeOwned, Deserializer, IntoDeserializer, Visitor};
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::forward_to_deserialize_any;
+
+use crate::error::Error;
+use super::Table;
+
+impl Table {
+    /// Replace headers of the given columns, handy when the page headers are too
+    /// vague to be matched with field names.
+    pub fn rename_headers(&mut self, names: &[(usize, &str)]) {
+        for (i, n) in names {
+            if let Some(h) = self.headers.get_mut(i) {
+                *h = n.to_string();
+            }
+        }
+    }
+
+    /// Deserialize every row of the table into `T`, failing on the first row that
+    /// does not fit.
+    ///
+    /// Columns are matched to serde field names and aliases by their header. Both
+    /// sides are compared as lowercase words so `#[serde(alias = "Alpha-2 code")]`
+    /// picks up a column with header `alpha-2 Code`. Columns without a matching
+    /// field are ignored.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> crate::Result<Vec<T>> {
+        self.deserialize_rows().into_iter().collect()
+    }
+
+    /// Same as `deserialize` but returns outcome of each row separately for the
+    /// callers who'd rather skip broken rows than the whole table.
+    pub fn deserialize_rows<T: DeserializeOwned>(&self) -> Vec<crate::Result<T>> {
+        self.rows.iter()
+            .enumerate()
+            .map(|(i, r)|T::deserialize(Row { headers: &self.headers, cells: r })
+                .map_err(|e| match e {
+                    RowError::Missing(c) => Error::MissingValue {
+                        url: self.source.to_owned(), table: self.index, row: i, column: c
+                    },
+                    RowError::Invalid(m) => Error::InvalidRow {
+                        url: self.source.to_owned(), table: self.index, row: i, message: m
+                    },
+                })
+            )
+            .collect()
+    }
+}
+
+/// Header or field name turned into lowercase words separated by underscores.
+/// Footnote markers like `[5]` are left out.
+pub fn header_key(s: &str) -> String {
+    let mut key = String::new();
+    let mut bracket = false;
+
+    for c in s.chars() {
+        match c {
+            '[' => bracket = true,
+            ']' => bracket = false,
+            _ if bracket => (),
+            c if c.is_alphanumeric() => key.extend(c.to_lowercase()),
+            _ => if ! key.is_empty() && ! key.ends_with('_') {
+                key.push('_');
+            },
+        }
+    }
+
+    key.trim_end_matches('_').to_string()
+}
+
+/// Row level error telling missing values apart from the ones that were there but
+/// could not be read.
+#[derive(Debug)]
+enum RowError {
+    Missing(String),
+    Invalid(String),
+}
+
+impl Display for RowError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing(c) => write!(f, "column {} did not have a value", c),
+            Self::Invalid(m) => write!(f, "{}", m),
+        }
+    }
+}
+
+impl std::error::Error for RowError {}
+
+impl serde::de::Error for RowError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self::Invalid(msg.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self::Missing(field.to_string())
+    }
+}
+
+struct Row<'a> {
+    headers: &'a BTreeMap<usize, String>,
+    cells: &'a BTreeMap<usize, Vec<String>>,
+}
+
+impl Row<'_> {
+    fn cell(&self, i: usize, column: &str) -> Cell {
+        let values = match self.cells.get(&i) {
+            Some(v) => v.iter()
+                .map(|s|s.trim().to_string())
+                .filter(|s|! s.is_empty())
+                .collect(),
+            None => vec![],
+        };
+
+        Cell { column: column.to_string(), values }
+    }
+}
+
+impl<'de> Deserializer<'de> for Row<'_> {
+    type Error = RowError;
+
+    // Maps get the headers as they are
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RowError> {
+        let pairs = self.headers.iter()
+            .map(|(i, h)|(h.to_owned(), self.cell(*i, h)))
+            .collect::<Vec<_>>();
+
+        visitor.visit_map(MapDeserializer::new(pairs.into_iter()))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(self, _name: &'static str, fields: &'static [&'static str],
+    visitor: V) -> Result<V::Value, RowError> {
+        let mut pairs = vec![];
+
+        for (i, h) in self.headers.iter() {
+            let key = header_key(h);
+
+            if let Some(f) = fields.iter().find(|f|header_key(f).eq(&key)) {
+                pairs.push((*f, self.cell(*i, h)));
+            }
+        }
+
+        visitor.visit_map(MapDeserializer::new(pairs.into_iter()))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Text values of a single table cell. Plain values are read from the first one,
+/// sequences get them all.
+struct Cell {
+    column: String,
+    values: Vec<String>,
+}
+
+impl Cell {
+    fn first(&self) -> Result<&str, RowError> {
+        match self.values.first() {
+            Some(s) => Ok(s),
+            None => Err(RowError::Missing(self.column.to_owned())),
+        }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, RowError> for Cell {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RowError> {
+                let s = self.first()?;
+
+                match s.replace(',', "").parse() {
+                    Ok(n) => visitor.$visit(n),
+                    Err(e) => Err(RowError::Invalid(format!("failed to read '{}' \
+                        of column {} as number: {}", s, self.column, e))),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Cell {
+    type Error = RowError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RowError> {
+        visitor.visit_string(self.first()?.to_string())
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RowError> {
+        match self.values.is_empty() {
+            true => visitor.visit_none(),
+            false => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RowError> {
+        visitor.visit_seq(SeqDeserializer::new(self.values.into_iter()))
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V)
+    -> Result<V::Value, RowError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(self, _name: &'static str, _variants: &'static [&'static str],
+    visitor: V) -> Result<V::Value, RowError> {
+        visitor.visit_enum(self.first()?.to_string().into_deserializer())
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, RowError> {
+        match self.first()?.to_lowercase().as_str() {
+            "yes" | "true" => visitor.visit_bool(true),
+            "no" | "false" => visitor.visit_bool(false),
+            s => Err(RowError::Invalid(format!("failed to read '{}' of column {} \
+                as boolean", s, self.column))),
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 char str string bytes byte_buf unit unit_struct tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::Tld;
+    use crate::scrape::extract;
+    use super::*;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Country {
+        #[serde(alias = "Alpha-2 code", deserialize_with = "extract::alpha2")]
+        code: String,
+        name: String,
+        #[serde(alias = "Numeric", deserialize_with = "extract::numeric")]
+        numeric: Option<u16>,
+        #[serde(alias = "ccTLD", deserialize_with = "extract::tld")]
+        tld: Option<Tld>,
+        population: Option<u32>,
+        languages: Vec<String>,
+    }
+
+    #[test]
+    fn header_keys_are_lowercase_words() {
+        assert_eq!(header_key("Alpha-2 code"), "alpha_2_code");
+        assert_eq!(header_key("  alpha-2 Code[5] "), "alpha_2_code");
+        assert_eq!(header_key("ISO 3166-1 numeric"), "iso_3166_1_numeric");
+    }
+
+    #[test]
+    fn deserializes_rows_by_headers_and_aliases() {
+        let t = Table::of_cells(&["alpha-2 Code[1]", "Name", "Numeric", "ccTLD", "Population", "Languages",
+            "Ignored"], &[
+            &[&["[a]", "FI"], &["Finland"], &["246"], &[".fi"], &["5,500,000"],
+                &["Finnish", "Swedish"], &["x"]],
+            &[&["EH"], &["Western Sahara"], &["-", "732"], &["none"], &[], &[], &[]],
+        ]);
+
+        let rows = t.deserialize::<Country>().unwrap();
+
+        assert_eq!(rows[0], Country {
+            code: "FI".into(), name: "Finland".into(), numeric: Some(246),
+            tld: Some(Tld::new(".fi").unwrap()), population: Some(5_500_000),
+            languages: vec!["Finnish".into(), "Swedish".into()]
+        });
+        // Cells without a dotted value have no domain, the row is kept
+        assert_eq!(rows[1].tld, None);
+        assert_eq!(rows[1].numeric, Some(732));
+        assert_eq!(rows[1].population, None);
+    }
+
+    #[test]
+    fn reports_row_and_column_of_missing_values() {
+        let t = Table::of_cells(&["Code", "Name", "Languages"], &[
+            &[&["FI"], &["Finland"], &[]],
+            &[&["SE"], &[], &[]],
+        ]);
+
+        #[derive(Deserialize, Debug)]
+        struct Named {
+            #[allow(dead_code)]
+            name: String,
+        }
+
+        let rows = t.deserialize_rows::<Named>();
+
+        assert!(rows[0].is_ok());
+        assert!(matches!(rows[1], Err(Error::MissingValue { table: 0, row: 1, ref column, .. })
+            if column == "Name"));
+        assert!(t.deserialize::<Named>().is_err());
+    }
+
+    #[test]
+    fn reports_values_that_can_not_be_read() {
+        let t = Table::of_cells(&["Population"], &[&[&["many"]]]);
+
+        #[derive(Deserialize, Debug)]
+        struct Counted {
+            #[allow(dead_code)]
+            population: u32,
+        }
+
+        assert!(matches!(t.deserialize::<Counted>(), Err(Error::InvalidRow { row: 0, .. })));
+    }
+
+    #[test]
+    fn renamed_headers_match_fields() {
+        let mut t = Table::of_cells(&["Country or region", "Code"], &[&[&["Finland"], &["358"]]]);
+        t.rename_headers(&[(0, "name"), (1, "calling_code")]);
+
+        #[derive(Deserialize)]
+        struct Renamed {
+            name: String,
+            calling_code: u16,
+        }
+
+        let rows = t.deserialize::<Renamed>().unwrap();
+
+        assert_eq!((rows[0].name.as_str(), rows[0].calling_code), ("Finland", 358));
+    }
+}
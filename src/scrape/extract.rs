@@ -0,0 +1,188 @@
+//! Value extractors for picking the right value out of a table cell holding more
+//! than one. Use them on fields of structs deserialized from `Table` rows:
+//!
+//! ```ignore
+//! #[serde(alias = "Alpha-3 code", deserialize_with = "extract::country_alpha3")]
+//! pub iso_3166_1_a3: Option<Alpha3>,
+//! ```
+use serde::{Deserialize, Deserializer};
+use serde::de::Error;
+
+use crate::{Alpha3, CallingCode, Endonym, Tld};
+
+fn values<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<String>, D::Error> {
+    Vec::<String>::deserialize(d)
+}
+
+/// Value of a field the row can do without, the reason it has none is reported
+fn reported<T>(val: std::result::Result<T, String>) -> Option<T> {
+    match val {
+        Ok(v) => Some(v),
+        Err(e) => {
+            eprintln!("{}", e);
+            None
+        }
+    }
+}
+
+fn required<E: Error>(val: Option<String>, what: &str) -> Result<String, E> {
+    match val {
+        Some(s) => Ok(s),
+        None => Err(E::custom(format!("cell did not have {}", what))),
+    }
+}
+
+/// Last value of the cell, footnotes and such tend to come first
+pub fn last<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    required(values(d)?.pop(), "a value")
+}
+
+/// Two letter code, ISO 3166-1 alpha-2 for example
+pub fn alpha2<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    required(sized(values(d)?, 2), "a two letter code")
+}
+
+/// Three letter code, ISO 3166-1 alpha-3 or ISO 4217 for example
+pub fn alpha3<'de, D: Deserializer<'de>>(d: D) -> Result<String, D::Error> {
+    required(sized(values(d)?, 3), "a three letter code")
+}
+
+/// ISO 3166-1 alpha-3 code, missing and malformed ones are reported and left out
+pub fn country_alpha3<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Alpha3>, D::Error> {
+    let v = values(d)?;
+
+    Ok(reported(sized(v.to_owned(), 3)
+        .ok_or(format!("Cell {:?} did not have a three letter code", v))
+        .and_then(|s|Alpha3::new(&s).map_err(|e|e.to_string()))))
+}
+
+/// Three letter code if there's one. Cells marked with `(none)` have no code.
+pub fn optional_alpha3<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
+    let v = values(d)?;
+
+    match v.join("").starts_with("(none)") {
+        true => Ok(None),
+        false => Ok(sized(v, 3)),
+    }
+}
+
+/// Three digit numeric code, ISO 3166-1 numeric for example. Missing ones are
+/// reported and left out.
+pub fn numeric<'de, D: Deserializer<'de>>(d: D) -> Result<Option<u16>, D::Error> {
+    let v = values(d)?;
+
+    Ok(reported(v.iter()
+        .find(|s|s.len() == 3 && s.chars().all(|c|c.is_ascii_digit()))
+        .ok_or(format!("Cell {:?} did not have a three digit code", v))
+        .and_then(|s|s.parse::<u16>().map_err(|e|e.to_string()))))
+}
+
+/// Top level domain starting with a dot, cells without one have no domain.
+/// Malformed ones are reported and left out.
+pub fn tld<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Tld>, D::Error> {
+    let s = match values(d)?.into_iter().find(|s|s.starts_with('.')) {
+        Some(s) => s,
+        None => return Ok(None),
+    };
+
+    Ok(reported(Tld::new(&s).map_err(|e|e.to_string())))
+}
+
+/// Calling code of the first value, malformed ones are reported and left out
+pub fn calling_code<'de, D: Deserializer<'de>>(d: D) -> Result<Option<CallingCode>, D::Error> {
+    let s = match values(d)?.into_iter().next() {
+        Some(s) => s,
+        None => return Ok(None),
+    };
+
+    Ok(reported(CallingCode::new(&s).map_err(|e|e.to_string())))
+}
+
+/// Number from the first value, values that can't be read as one are left out.
+pub fn optional_number<'de, D: Deserializer<'de>>(d: D) -> Result<Option<i32>, D::Error> {
+    let s = match values(d)?.into_iter().next() {
+        Some(s) => s,
+        None => return Ok(None),
+    };
+
+    match s.replace(',', "").parse::<i32>() {
+        Ok(u) => Ok(Some(u)),
+        Err(e) => {
+            eprintln!("Failed to read {} as number: {}", s, e);
+            Ok(None)
+        }
+    }
+}
+
+/// Endonyms of a cell holding `language tag, name` pairs. Names tagged as latin
+/// script are taken as romanizations when the same language has a name not tagged
+/// so, `ar-Latn` next to `ar` for example.
+pub fn endonyms<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<Endonym>, D::Error> {
+    let v = values(d)?;
+    let mut endonyms = v.chunks(2)
+        .filter(|p|p.len() == 2)
+        .map(|p|Endonym::new(&p[1], match p[0].as_str() {
+            "und" => None,
+            s => Some(s),
+        }))
+        .collect::<Vec<_>>();
+
+    endonyms.sort();
+    endonyms.dedup();
+
+    let native = endonyms.iter()
+        .filter(|e|e.script.as_deref().ne(&Some("Latn")))
+        .filter_map(|e|e.language().map(|s|s.to_string()))
+        .collect::<Vec<_>>();
+
+    for e in endonyms.iter_mut() {
+        let latin = e.script.as_deref().eq(&Some("Latn"));
+        let other = e.language().is_some_and(|l|native.iter().any(|n|n.eq(l)));
+
+        e.romanized = latin && other;
+    }
+
+    Ok(endonyms)
+}
+
+fn sized(v: Vec<String>, len: usize) -> Option<String> {
+    v.into_iter().find(|s|s.chars().count() == len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn names(endonyms: &[Endonym]) -> Vec<(&str, Option<&str>, bool)> {
+        endonyms.iter().map(|e|(e.name.as_str(), e.lang.as_deref(), e.romanized)).collect()
+    }
+
+    #[test]
+    fn latin_names_of_other_scripts_are_romanizations() {
+        let v = endonyms(json!(["ar", "مصر", "ar-Latn", "Miṣr"])).unwrap();
+
+        assert_eq!(names(&v), vec![("Miṣr", Some("ar-Latn"), true), ("مصر", Some("ar"), false)]);
+        assert_eq!(v[0].script.as_deref(), Some("Latn"));
+    }
+
+    #[test]
+    fn latin_names_without_other_scripts_are_not_romanizations() {
+        let v = endonyms(json!(["uz-Latn", "Oʻzbekiston", "fi", "Suomi"])).unwrap();
+
+        assert!(v.iter().all(|e|! e.romanized));
+
+        // Script of the other name doesn't matter as long as it's not latin
+        let v = endonyms(json!(["sr-Latn", "Srbija", "sr-Cyrl", "Србија"])).unwrap();
+
+        assert_eq!(names(&v), vec![("Srbija", Some("sr-Latn"), true), ("Србија", Some("sr-Cyrl"), false)]);
+    }
+
+    #[test]
+    fn untagged_names_have_no_language() {
+        let v = endonyms(json!(["und", "Suomi", "fi", "Suomi", "sv"])).unwrap();
+
+        // Pairs are tag and name, the dangling tag is left out
+        assert_eq!(names(&v), vec![("Suomi", None, false), ("Suomi", Some("fi"), false)]);
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use scraper::{ElementRef, Selector};
+
+use crate::error::{Error, Result};
+use super::{Source, Table};
+
+/// Read every table on the page as is. Unlike `table_scraper` nothing is filtered
+/// out which makes this useful for finding out what the scrapers are looking at.
+pub fn inspect_tables(source: Source<'_>) -> Result<Vec<Table>> {
+    let document = source.document()?;
+    let table_selector = Selector::parse("table").unwrap();
+    let tr_selector = Selector::parse("tr").unwrap();
+    let cell_selector = Selector::parse("th, td").unwrap();
+    let th_selector = Selector::parse("th").unwrap();
+    let td_selector = Selector::parse("td").unwrap();
+
+    let mut tables = vec![];
+
+    for (g, x) in document.select(&table_selector).enumerate() {
+        let mut table = Table::new(x, &source, g);
+
+        for r in x.select(&tr_selector) {
+            // First row made of headers only is the header row
+            if table.headers.is_empty() && r.select(&td_selector).next().is_none() {
+                table.headers = r.select(&th_selector)
+                    .enumerate()
+                    .map(|(i, h)|(i, cell_text(h).join(" ")))
+                    .collect();
+
+                continue;
+            }
+
+            if r.select(&td_selector).next().is_none() {
+                continue;
+            }
+
+            table.rows.push(
+                r.select(&cell_selector)
+                    .enumerate()
+                    .map(|(i, c)|(i, cell_text(c)))
+                    .collect::<BTreeMap<usize, Vec<String>>>()
+            );
+        }
+
+        tables.push(table);
+    }
+
+    Ok(tables)
+}
+
+impl Table {
+    /// Widest row (headers included) of the table
+    pub fn columns(&self) -> usize {
+        self.rows.iter()
+            .map(|r|r.len())
+            .chain([self.headers.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Cell values of a row joined into a single string per column
+    pub fn row_values(&self, row: usize) -> Vec<String> {
+        let r = match self.rows.get(row) {
+            Some(r) => r,
+            None => return vec![],
+        };
+
+        (0..self.columns())
+            .map(|i|match r.get(&i) {
+                Some(v) => v.join(" "),
+                None => String::new(),
+            })
+            .collect()
+    }
+
+    pub fn to_csv(&self) -> Result<String> {
+        let mut writer = csv::WriterBuilder::new()
+            .flexible(true)
+            .from_writer(vec![]);
+
+        let columns = self.columns();
+
+        if ! self.headers.is_empty() {
+            let headers = (0..columns)
+                .map(|i|self.headers.get(&i).cloned().unwrap_or_default());
+
+            if let Err(e) = writer.write_record(headers) {
+                return Err(self.export_error("CSV", e));
+            }
+        }
+
+        for i in 0..self.rows.len() {
+            if let Err(e) = writer.write_record(self.row_values(i)) {
+                return Err(self.export_error("CSV", e));
+            }
+        }
+
+        let bytes = writer.into_inner()
+            .map_err(|e| self.export_error("CSV", e))?;
+
+        String::from_utf8(bytes)
+            .map_err(|e| self.export_error("CSV", e))
+    }
+
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| self.export_error("JSON", e))
+    }
+
+    fn export_error(&self, format: &str, e: impl ToString) -> Error {
+        Error::Export { table: self.index, format: format.to_string(), message: e.to_string() }
+    }
+}
+
+fn cell_text(el: ElementRef<'_>) -> Vec<String> {
+    el.text()
+        .map(|s|s.trim())
+        .filter(|s|! s.is_empty())
+        .map(|s|s.to_string())
+        .collect()
+}
@@ -1,28 +1,151 @@
 pub mod wikipedia;
+pub mod inspect;
+pub mod de;
+pub mod extract;
 
+use std::borrow::Cow;
 use std::collections::BTreeMap;
-use anyhow::{Result, bail};
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::path::Path;
 use scraper::*;
+use serde::Serialize;
 use std::ops::Range;
 
-#[derive(Debug, Clone)]
+use crate::error::{Error, Result};
+use crate::http::http_get;
+
+/// Where the html to scrape tables from is read from. Urls are fetched over http,
+/// files are read from disk and html strings are parsed as is.
+#[derive(Debug, Clone, Copy)]
+pub enum Source<'a> {
+    Url(&'a str),
+    File(&'a Path),
+    Html(&'a str),
+    Document(&'a Html),
+}
+
+impl<'a> Source<'a> {
+    /// Read (and parse when needed) the html document behind the source
+    pub fn document(&self) -> Result<Cow<'a, Html>> {
+        match *self {
+            Self::Url(u) => http_client_reads(u).map(Cow::Owned),
+            Self::File(p) => file_reads(p).map(Cow::Owned),
+            Self::Html(s) => Ok(Cow::Owned(Html::parse_document(s))),
+            Self::Document(d) => Ok(Cow::Borrowed(d)),
+        }
+    }
+}
+
+impl Display for Source<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Url(u) => write!(f, "{}", u),
+            Self::File(p) => write!(f, "{}", p.display()),
+            Self::Html(_) => write!(f, "html string"),
+            Self::Document(_) => write!(f, "html document"),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
 pub struct Table {
+    /// Url, file path or other description of where the table was read from
+    pub source: String,
+    /// Position of the table among all tables on the page
+    pub index: usize,
+    pub caption: Option<String>,
+    /// Text of the closest section heading preceding the table
+    pub heading: Option<String>,
+    pub id: Option<String>,
+    pub classes: Vec<String>,
     pub headers: BTreeMap<usize, String>,
     pub rows: Vec<BTreeMap<usize, Vec<String>>>
 }
 
 impl Table {
-    fn new(headers: BTreeMap<usize, String>) -> Self {
-        Self { headers, rows: vec![] }
+    fn new(el: ElementRef<'_>, source: &Source<'_>, index: usize) -> Self {
+        let caption = el.children()
+            .filter_map(ElementRef::wrap)
+            .find(|c|c.value().name() == "caption")
+            .map(element_text)
+            .filter(|s|! s.is_empty());
+
+        Self {
+            source: source.to_string(), index, caption, heading: preceding_heading(el),
+            id: el.value().id().map(|s|s.to_string()),
+            classes: el.value().classes().map(|s|s.to_string()).collect(),
+            headers: BTreeMap::new(), rows: vec![]
+        }
+    }
+
+    /// Table of the headers and rows of cell values, for tests reading tables
+    #[cfg(test)]
+    pub(crate) fn of_cells(headers: &[&str], rows: &[&[&[&str]]]) -> Self {
+        Self {
+            source: "test".into(), index: 0, caption: None, heading: None, id: None,
+            classes: vec![],
+            headers: headers.iter().enumerate().map(|(i, h)|(i, h.to_string())).collect(),
+            rows: rows.iter()
+                .map(|r|r.iter()
+                    .enumerate()
+                    .map(|(i, c)|(i, c.iter().map(|s|s.to_string()).collect()))
+                    .collect())
+                .collect(),
+        }
+    }
+}
+
+/// Narrows down the html tables scraped from a page. All given filters must match.
+#[derive(Debug, Clone)]
+pub enum TableFilter {
+    /// Table position among all tables on the page
+    Index(Range<usize>),
+    /// Css selector the table element itself must match, `table.wikitable` etc.
+    Selector(String),
+    /// Closest preceding section heading equals one of these (case insensitive)
+    Heading(Vec<String>),
+    /// Table caption contains the text (case insensitive)
+    Caption(String),
+}
+
+impl TableFilter {
+    fn matches(&self, el: ElementRef<'_>, table: &Table) -> Result<bool> {
+        let found = match self {
+            Self::Index(r) => r.contains(&table.index),
+            Self::Selector(s) => match Selector::parse(s) {
+                Ok(v) => v.matches(&el),
+                Err(e) => return Err(Error::InvalidSelector {
+                    selector: s.to_owned(), message: e.to_string()
+                }),
+            },
+            Self::Heading(v) => match table.heading {
+                Some(ref h) => v.iter().any(|s|s.to_lowercase().eq(&h.to_lowercase())),
+                None => false,
+            },
+            Self::Caption(s) => match table.caption {
+                Some(ref c) => c.to_lowercase().contains(&s.to_lowercase()),
+                None => false,
+            },
+        };
+
+        Ok(found)
     }
 }
 
 pub fn table_scraper(url: &str, columns: usize, discard_col_indexes: Vec<usize>,
-fixed_table_index_range: Option<Range<usize>>, parser: impl Fn(ElementRef<'_>, usize) -> Option<Vec<&str>>)
+filters: Vec<TableFilter>, parser: impl Fn(ElementRef<'_>, usize) -> Option<Vec<&str>>)
+-> Result<Vec<Table>> {
+    source_table_scraper(Source::Url(url), columns, discard_col_indexes,
+        filters, parser)
+}
+
+pub fn source_table_scraper(source: Source<'_>, columns: usize, discard_col_indexes: Vec<usize>,
+filters: Vec<TableFilter>, parser: impl Fn(ElementRef<'_>, usize) -> Option<Vec<&str>>)
 -> Result<Vec<Table>> {
-    println!("Scrape table data from {}...", url);
+    println!("Scrape table data from {}...", source);
 
-    let document = http_client_reads(url)?;
+    let document = source.document()?;
     let table_selector = Selector::parse("table").unwrap();
     let tr_selector = Selector::parse("tr").unwrap();
     let th_selector = Selector::parse("th").unwrap();
@@ -33,21 +156,24 @@ fixed_table_index_range: Option<Range<usize>>, parser: impl Fn(ElementRef<'_>, u
     let table_elements = document.select(&table_selector).collect::<Vec<ElementRef<'_>>>();
 
     if table_elements.is_empty() {
-        bail!("Failed to find tables from {}", url);
+        return Err(Error::TableNotFound { url: source.to_string(), what: "any".into() })
     }
 
     // Collect matches to vector of scraped objects
     let mut scraped = vec![];
 
-    for (g, x) in table_elements.iter().enumerate() {
-        if let Some(ref r) = fixed_table_index_range {
-            if ! r.contains(&g) {
-                println!("Skipping table number {} as it's not within the search range", g);
+    'table: for (g, x) in table_elements.iter().enumerate() {
+        let mut table = Table::new(*x, &source, g);
 
-                continue;
+        for f in filters.iter() {
+            if ! f.matches(*x, &table)? {
+                println!("Skipping table number {} as it does not match {:?}", g, f);
+
+                continue 'table;
             }
         }
-        println!("Iterating html table {}/{} on {}", g + 1, table_elements.len(), url);
+
+        println!("Iterating html table {}/{} on {}", g + 1, table_elements.len(), source);
         let mut header_map = BTreeMap::new();
 
         // Rows in table
@@ -83,7 +209,7 @@ fixed_table_index_range: Option<Range<usize>>, parser: impl Fn(ElementRef<'_>, u
             continue;
         }
 
-        let mut table = Table::new(header_map);
+        table.headers = header_map;
 
         // Now that we have the headers loop the rows again, this time for content
         for r in tr_elements.into_iter() {
@@ -95,7 +221,7 @@ fixed_table_index_range: Option<Range<usize>>, parser: impl Fn(ElementRef<'_>, u
 
             for (i, c) in data.into_iter().enumerate() {
                 // Make sure this is not discarded column
-                if table.headers.get(&i).is_none() {
+                if ! table.headers.contains_key(&i) {
                     continue;
                 }
     
@@ -118,22 +244,23 @@ fixed_table_index_range: Option<Range<usize>>, parser: impl Fn(ElementRef<'_>, u
 
         scraped.push(table);
     }
-    
-    return Ok(scraped)
+
+    Ok(scraped)
 }
 
 fn http_client_reads(url: &str) -> Result<Html> {
-    let resp = match reqwest::blocking::get(url) {
-        Ok(r) => r,
-        Err(e) => bail!("Failed to read http document {}: {}", url, e),
-    };
+    let html = http_get(url)?;
+
+    // Use scraper to build readable html from response data
+    Ok(Html::parse_document(&html))
+}
 
-    let html = match resp.text() {
+fn file_reads(path: &Path) -> Result<Html> {
+    let html = match read_to_string(path) {
         Ok(s) => s,
-        Err(e) => bail!("Failed to open http document from: {}", e),
+        Err(e) => return Err(Error::File { path: path.to_owned(), source: e }),
     };
 
-    // Use scraper to build readable html from response data
     Ok(Html::parse_document(&html))
 }
 
@@ -144,3 +271,111 @@ fn map_header(map: &mut BTreeMap<usize, String>, el: ElementRef<'_>, i: usize) {
         map.insert(i, s.trim_end().to_string());
     }
 }
+
+/// Walk backwards from the element (and up its ancestors) until a section heading
+/// is found. Wikipedia wraps headings in divs these days so look inside siblings too.
+fn preceding_heading(el: ElementRef<'_>) -> Option<String> {
+    let heading_selector = Selector::parse("h1, h2, h3, h4, h5, h6").unwrap();
+    let mut node = *el;
+
+    loop {
+        for s in node.prev_siblings() {
+            let sibling = match ElementRef::wrap(s) {
+                Some(e) => e,
+                None => continue,
+            };
+
+            let heading = match heading_selector.matches(&sibling) {
+                true => Some(sibling),
+                false => sibling.select(&heading_selector).last(),
+            };
+
+            if let Some(h) = heading {
+                return Some(heading_text(h));
+            }
+        }
+
+        node = node.parent()?;
+    }
+}
+
+fn heading_text(el: ElementRef<'_>) -> String {
+    // Older wikipedia markup has the [edit] links inside the heading element
+    let headline_selector = Selector::parse(".mw-headline").unwrap();
+
+    match el.select(&headline_selector).next() {
+        Some(h) => element_text(h),
+        None => element_text(el),
+    }
+}
+
+fn element_text(el: ElementRef<'_>) -> String {
+    el.text().collect::<String>().trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = r#"<html><body>
+        <h2>Countries</h2>
+        <table class="wikitable">
+            <caption>Codes</caption>
+            <tr><th><a title="Alpha-2 code">A-2</a></th><th>Name</th><th>Notes</th></tr>
+            <tr><td>FI</td><td>Finland</td><td>[1]</td></tr>
+            <tr><td>SE</td><td>Sweden <sup> </sup></td><td></td></tr>
+            <tr><td colspan="3">Not a row of three cells</td></tr>
+        </table>
+        <table><tr><th>Only</th><th>two</th></tr><tr><td>a</td><td>b</td></tr></table>
+    </body></html>"#;
+
+    fn text(c: ElementRef<'_>, _: usize) -> Option<Vec<&str>> {
+        Some(c.text().collect())
+    }
+
+    #[test]
+    fn scrapes_tables_of_html_string() {
+        let tables = source_table_scraper(Source::Html(HTML), 3, vec![2], vec![], text).unwrap();
+
+        assert_eq!(tables.len(), 1);
+
+        let t = &tables[0];
+
+        assert_eq!(t.index, 0);
+        assert_eq!(t.source, "html string");
+        assert_eq!(t.caption.as_deref(), Some("Codes"));
+        assert_eq!(t.heading.as_deref(), Some("Countries"));
+        assert_eq!(t.classes, vec!["wikitable"]);
+        // Link titles win over the header text, discarded columns are left out
+        assert_eq!(t.headers, BTreeMap::from([(0, "Alpha-2 code".to_string()), (1, "Name".to_string())]));
+        assert_eq!(t.rows.len(), 2);
+        assert_eq!(t.rows[0][&0], vec!["FI"]);
+        assert_eq!(t.rows[1][&1], vec!["Sweden "]);
+    }
+
+    #[test]
+    fn scrapes_parsed_documents_and_files() {
+        let document = Html::parse_document(HTML);
+        let tables = source_table_scraper(Source::Document(&document), 2, vec![], vec![], text)
+            .unwrap();
+
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].index, 1);
+        assert_eq!(tables[0].rows[0][&1], vec!["b"]);
+
+        let path = std::env::temp_dir().join("bubbles-scrape-test.html");
+        std::fs::write(&path, HTML).unwrap();
+
+        let tables = source_table_scraper(Source::File(&path), 3, vec![], vec![], text).unwrap();
+
+        assert_eq!(tables[0].source, path.display().to_string());
+        assert_eq!(tables[0].rows[0][&2], vec!["[1]"]);
+    }
+
+    #[test]
+    fn fails_without_tables() {
+        let e = source_table_scraper(Source::Html("<p>nothing</p>"), 3, vec![], vec![], text);
+
+        assert!(matches!(e, Err(Error::TableNotFound { .. })));
+    }
+}
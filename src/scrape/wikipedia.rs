@@ -1,236 +1,173 @@
-use anyhow::{Result, bail};
 use scraper::{ElementRef, Selector};
 use serde::Deserialize;
+use serde::de::DeserializeOwned;
 
+use crate::{Alpha3, CallingCode, Endonym, Flag, Tld};
+use crate::error::{Error, Result};
+use super::{Source, Table, TableFilter, extract};
 
-#[derive(Deserialize, Default, Debug)]
+
+#[derive(Deserialize, Debug)]
 pub struct WikipediaScrapedCountry {
+    #[serde(alias = "ISO 3166 name", alias = "ISO 3166")]
     pub exonym: String,
+    #[serde(alias = "Official state name")]
     pub name: String,
+    #[serde(alias = "Alpha-2 code", alias = "ISO 3166-1 alpha-2", alias = "A-2",
+        deserialize_with = "extract::alpha2")]
     pub iso_3166_1_a2: String,
-    pub iso_3166_1_a3: String,
-    pub tld: String
+    #[serde(alias = "Alpha-3 code", alias = "ISO 3166-1 alpha-3", alias = "A-3",
+        deserialize_with = "extract::country_alpha3")]
+    pub iso_3166_1_a3: Option<Alpha3>,
+    #[serde(alias = "Numeric code", alias = "ISO 3166-1 numeric", alias = "Numeric",
+        deserialize_with = "extract::numeric")]
+    pub iso_3166_1_numeric: Option<u16>,
+    #[serde(alias = "Internet ccTLD", alias = "Country code top-level domain",
+        deserialize_with = "extract::tld")]
+    pub tld: Option<Tld>
 }
 
 impl WikipediaScrapedCountry {
     pub fn scrape(url: &str) -> Result<Vec<Self>> {
+        Self::scrape_source(Source::Url(url))
+    }
+
+    pub fn scrape_source(source: Source<'_>) -> Result<Vec<Self>> {
+        Self::from_tables(&Self::tables(source)?)
+    }
+
+    /// The ISO 3166-1 countries table as found from the source, before reading it
+    pub fn tables(source: Source<'_>) -> Result<Vec<Table>> {
         println!("Scrape ISO 3166-1 country data from wikipedia...");
     
         // Define takes and discards
         let columns = 8;
-        let discard_col_indexes = vec![2, 5, 6];
+        let discard_col_indexes = vec![2, 6];
     
-        let table = match super::table_scraper(url, columns, discard_col_indexes, None, common_parser) {
-            Ok(v) => match v.len() == 1 {
-                true => v[0].to_owned(),
-                false => bail!("Found more than one tables with same number of columns"),
-            },
-            Err(e) => bail!("Failed to find (or read) ISO 3166-1 countries table from wikipedia {}", e),
-        };
-
-        let mut scraped = vec![];
+        let tables = super::source_table_scraper(source, columns, discard_col_indexes, vec![], common_parser)?;
 
-        for r in table.rows {
-            let mut cs = Self::default();
-
-            for (i, v) in r.into_iter() {
-                let val = match v.len() == 1 {
-                    true => Some(v[0].to_owned()),
-                    false => match i {
-                        3 => v.into_iter().find(|s|s.len() == 2),
-                        4 => v.into_iter().find(|s|s.len() == 3),
-                        7 => v.into_iter().find(|s|s.starts_with(".")),
-                        _ => v.into_iter().next(),
-                    }
-                };
-    
-                match val {
-                    Some(s) => {
-                        let v = s.trim_end().to_string();
-                        match i {
-                            0 => cs.exonym = v,
-                            1 => cs.name = v,
-                            3 => cs.iso_3166_1_a2 = v,
-                            4 => cs.iso_3166_1_a3 = v,
-                            7 => cs.tld = v,
-                            _ => bail!("Stupid developer with mixed indexes {}", url),
-                        }
-                    },
-                    None => bail!("Well, shit, column index {} from header {} did \
-                        not have a value", i, table.headers.get(&i).unwrap())
-                }
-            }
-            scraped.push(cs);
-        }
+        Ok(vec![single_table(tables, &source, "ISO 3166-1 countries")?])
+    }
 
-        Ok(scraped)
+    /// Countries without an alpha-2 code are reported and left out, other
+    /// malformed values just the value
+    pub fn from_tables(tables: &[Table]) -> Result<Vec<Self>> {
+        Ok(deserialize_valid_rows(tables, "country"))
     }
 }
 
 #[derive(Deserialize, Default, Debug)]
 pub struct WikipediaScrapedCurrency {
+    #[serde(alias = "State or territory")]
     pub exonym: String,
+    #[serde(alias = "Currency")]
     pub name: String,
+    #[serde(alias = "ISO code", alias = "ISO 4217", default,
+        deserialize_with = "extract::optional_alpha3")]
     pub iso_4217: Option<String>,
+    #[serde(alias = "Symbol", alias = "Currency symbol")]
     pub symbol: Option<String>,
+    #[serde(alias = "Fractional unit", alias = "Denomination (currency)")]
     pub fraction: Option<String>,
+    #[serde(alias = "Number to basic", default,
+        deserialize_with = "extract::optional_number")]
     pub fractions_in_unit: Option<i32>,
 }
 
 impl WikipediaScrapedCurrency {
     pub fn scrape(url: &str) -> Result<Vec<Self>> {
-        println!("Scrape circulating currencies data from wikipedia...");
+        Self::scrape_source(Source::Url(url))
+    }
 
+    pub fn scrape_source(source: Source<'_>) -> Result<Vec<Self>> {
+        Self::from_tables(&Self::tables(source)?)
+    }
+
+    /// The circulating currencies table as found from the source, before reading it
+    pub fn tables(source: Source<'_>) -> Result<Vec<Table>> {
+        println!("Scrape circulating currencies data from wikipedia...");
+    
         // Define takes and discards
         let columns = 6;
         let discard_col_indexes = vec![];
     
-        let table = match super::table_scraper(url, columns, discard_col_indexes, None, common_parser) {
-            Ok(v) => match v.len() == 1 {
-                true => v[0].to_owned(),
-                false => bail!("Found more than one tables with same number of columns"),
-            },
-            Err(e) => bail!("Failed to find (or read) circulating currencies table from wikipedia {}", e),
-        };
-
-        let mut scraped = vec![];
+        let tables = super::source_table_scraper(source, columns, discard_col_indexes, vec![], common_parser)?;
 
-        for r in table.rows {
-            let mut cs = Self::default();
-
-            for (i, v) in r.into_iter() {
-                // The '(none)' case
-                let hasval = ! (v.len() >= 3 && v.join("").starts_with("(none)"));
-
-                let val = match v.len() == 1 {
-                    true => Some(v[0].to_owned()),
-                    false => match i {
-                        3 => v.into_iter().find(|s|hasval && s.len() == 3),
-                        _ => v.into_iter().next(),
-                    }
-                };
-
-                match val {
-                    Some(s) => {
-                        let v = s.trim_end().to_string();
-                        let sommed = Some(v.to_owned());
-
-                        match i {
-                            0 => cs.exonym = v,
-                            1 => cs.name = v,
-                            2 => cs.symbol = sommed,
-                            3 => cs.iso_4217 = sommed,
-                            4 => cs.fraction = sommed,
-                            5 => cs.fractions_in_unit = match v.parse::<i32>() {
-                                Ok(u) => Some(u),
-                                Err(e) => {
-                                    eprintln!("Failed to read {} as number: {}", s, e);    
-                                    None
-                                }
-                            },
-                            _ => bail!("Stupid developer with mixed indexes {}", url),
-                        }
-                    },
-                    None => match i {
-                        2 => cs.symbol = None,
-                        3 => cs.iso_4217 = None,
-                        4 => cs.fraction = None,
-                        5 => cs.fractions_in_unit = None,
-                        x => bail!("Well, shit, column index {} from header {} \
-                            did not have a value", x, table.headers.get(&x).unwrap())
-                    }
-                }
-            }
-            scraped.push(cs);
-        }
+        Ok(vec![single_table(tables, &source, "circulating currencies")?])
+    }
 
-        Ok(scraped)
+    pub fn from_tables(tables: &[Table]) -> Result<Vec<Self>> {
+        deserialize_tables(tables)
     }
 }
 
-#[derive(Deserialize, Default, Debug)]
+#[derive(Deserialize, Debug)]
 pub struct WikipediaScrapedFlag {
+    #[serde(alias = "Code", alias = "ISO 3166-1 alpha-2", alias = "Alpha-2 code",
+        deserialize_with = "extract::alpha2")]
     pub iso_3166_1_a2: String,
-    pub flag: String,
+    #[serde(alias = "Emoji", alias = "Flag")]
+    pub flag: Flag,
 }
 
 impl WikipediaScrapedFlag {
     pub fn scrape(url: &str) -> Result<Vec<Self>> {
-        println!("Scrape flag emojis data from wikipedia...");
+        Self::scrape_source(Source::Url(url))
+    }
 
+    pub fn scrape_source(source: Source<'_>) -> Result<Vec<Self>> {
+        Self::from_tables(&Self::tables(source)?)
+    }
+
+    /// The country flags table as found from the source, before reading it
+    pub fn tables(source: Source<'_>) -> Result<Vec<Table>> {
+        println!("Scrape flag emojis data from wikipedia...");
+    
         // Define takes and discards
         let columns = 4;
         let discard_col_indexes = vec![2, 3];
     
-        let table = match super::table_scraper(url, columns, discard_col_indexes, None, common_parser) {
-            Ok(v) => match v.len() == 1 {
-                true => v[0].to_owned(),
-                false => bail!("Found more than one tables with same number of columns"),
-            },
-            Err(e) => bail!("Failed to find (or read) country flags table from wikipedia {}", e),
-        };
-
-        let mut scraped = vec![];
+        let tables = super::source_table_scraper(source, columns, discard_col_indexes, vec![], common_parser)?;
 
-        for r in table.rows {
-            let mut cs = Self::default();
-
-            for (i, v) in r.into_iter() {
-                let val = match i {
-                    0 => v.into_iter().next(),
-                    1 => v.into_iter().find(|s|s.len() == 2),
-                    _ => bail!("Stupid developer with mixed indexes {}", url),
-                };
-
-                // let val = match v.len() == 1 {
-                //     true => Some(v[0].to_owned()),
-                //     false => v.into_iter().next(),
-                // };
-
-                match val {
-                    Some(s) => {
-                        let v = s.trim_end().to_string();
-
-                        match i {
-                            0 => cs.flag = v,
-                            1 => cs.iso_3166_1_a2 = v,
-                            _ => bail!("Stupid developer with mixed indexes {}", url),
-                        }
-                    },
-                    None => bail!("Well, shit, column index {} from header {} did \
-                        not have a value", i, table.headers.get(&i).unwrap()),
-                }
-            }
-            scraped.push(cs);
-        }
+        Ok(vec![single_table(tables, &source, "country flags")?])
+    }
 
-        Ok(scraped)
+    /// Malformed flags are reported and left out
+    pub fn from_tables(tables: &[Table]) -> Result<Vec<Self>> {
+        Ok(deserialize_valid_rows(tables, "flag"))
     }
 }
 
-#[derive(Deserialize, Default, Debug)]
+#[derive(Deserialize, Debug)]
 pub struct WikipediaScrapedCcTz {
+    #[serde(deserialize_with = "extract::last")]
     pub exonym: String,
-    pub code: String,
+    #[serde(deserialize_with = "extract::calling_code")]
+    pub code: Option<CallingCode>,
     pub tz: String,
     pub dst: Option<String>
 }
 
 impl WikipediaScrapedCcTz {
     pub fn scrape(url: &str) -> Result<Vec<Self>> {
+        Self::scrape_source(Source::Url(url))
+    }
+
+    pub fn scrape_source(source: Source<'_>) -> Result<Vec<Self>> {
+        Self::from_tables(&Self::tables(source)?)
+    }
+
+    /// Calling codes and timezones tables as found from the source, before
+    /// reading them
+    pub fn tables(source: Source<'_>) -> Result<Vec<Table>> {
         println!("Scrape calling code and timezone data from wikipedia...");
 
         // Define takes and discards
         let columns = 4;
         let discard_col_indexes = vec![];
     
-        let tables = match super::table_scraper(url, columns, discard_col_indexes, None, common_parser) {
-            Ok(v) => v,
-            Err(e) => bail!("Failed to find (or read) calling codes and \
-                timezones table from wikipedia {}", e),
-        };
-
-        let mut scraped = vec![];
+        let tables = super::source_table_scraper(source, columns, discard_col_indexes, vec![], common_parser)?;
+        let mut familiar = vec![];
 
         for t in tables {
             // Let's try to determine if this is our table by reading the headers
@@ -245,46 +182,32 @@ impl WikipediaScrapedCcTz {
                 continue;
             }
 
-            'row: for r in t.rows {
-                let mut cs = Self::default();
-    
-                for (i, v) in r.into_iter() {
-                    let val = match v.len() == 1 {
-                        true => Some(v[0].to_owned()),
-                        false => match i == 0 {
-                            true => v.into_iter().last(),
-                            false => v.into_iter().next(),
-                        },
-                    };
-    
-                    match val {
-                        Some(s) => {
-                            let v = s.trim_end().to_string();
-    
-                            match i {
-                                0 => cs.exonym = v,
-                                1 => cs.code = v,
-                                // Empty timezone seems to be non country calling code
-                                2 => cs.tz = match v.is_empty() {
-                                    true => continue 'row,
-                                    false => v,
-                                },
-                                3 => cs.dst = match v.is_empty() {
-                                    true => None,
-                                    false => Some(v),
-                                },
-                                _ => bail!("Stupid developer with mixed indexes {}", url),
-                            }
-                        },
-                        None => match i {
-                            2 => continue 'row,
-                            3 => cs.dst = None,
-                            x => bail!("Well, shit, column index {} from header {} \
-                                did not have a value", x, t.headers.get(&x).unwrap())
-                        },
-                    }
+            familiar.push(t);
+        }
+
+        if familiar.is_empty() {
+            return Err(Error::TableNotFound {
+                url: source.to_string(), what: "calling codes and timezones".into()
+            })
+        }
+
+        Ok(familiar)
+    }
+
+    pub fn from_tables(tables: &[Table]) -> Result<Vec<Self>> {
+        let mut scraped = vec![];
+
+        for t in tables {
+            // Headers are familiar but worded loosely, name them after our fields
+            let mut t = t.to_owned();
+            t.rename_headers(&[(0, "exonym"), (1, "code"), (2, "tz"), (3, "dst")]);
+
+            // Empty timezone seems to be non country calling code
+            for r in t.deserialize_rows() {
+                match r {
+                    Ok(v) => scraped.push(v),
+                    Err(e) => println!("Skipping calling code without timezone: {}", e),
                 }
-                scraped.push(cs);
             }
         }
 
@@ -297,63 +220,162 @@ impl WikipediaScrapedCcTz {
 pub struct WikipediaScrapedEndonyms {
     pub exonym: String,
     pub capital: String,
-    pub endonyms: Vec<String>,
+    #[serde(default, deserialize_with = "extract::endonyms")]
+    pub capital_endonyms: Vec<Endonym>,
+    #[serde(deserialize_with = "extract::endonyms")]
+    pub endonyms: Vec<Endonym>,
     pub languages: Vec<String>
 }
 
 impl WikipediaScrapedEndonyms {
     pub fn scrape(url: &str) -> Result<Vec<Self>> {
+        Self::scrape_source(Source::Url(url))
+    }
+
+    pub fn scrape_source(source: Source<'_>) -> Result<Vec<Self>> {
+        Self::from_tables(&Self::tables(source)?)
+    }
+
+    /// Endonyms and languages tables as found from the source, before reading them
+    pub fn tables(source: Source<'_>) -> Result<Vec<Table>> {
         println!("Scrape local country names and spoken languages data from wikipedia...");
 
         // Define takes and discards
         let columns = 5;
-        let discard_col_indexes = vec![3];
-        
-        // One table per alphabet, collecting countries by their exonym
-        let tables = match super::table_scraper(url, columns, discard_col_indexes, None, endonym_lang_parser) {
-            Ok(v) => v,
-            Err(e) => bail!("Failed to find (or read) endonyms and languages table from wikipedia {}", e),
-        };
+        let discard_col_indexes = vec![];
 
-        let mut scraped = vec![];
+        // Alphabet sections from A to Z hold the tables we're after
+        let sections = TableFilter::Heading(('A'..='Z').map(|c|c.to_string()).collect());
+
+        // One table per alphabet, collecting countries by their exonym
+        let tables = super::source_table_scraper(source, columns, discard_col_indexes, vec![sections], endonym_lang_parser)?;
+        let mut familiar = vec![];
 
         for t in tables {
             // Let's try to determine if this is our table by reading the headers
             let country = t.headers.get(&0).unwrap().to_lowercase().contains("exonym");
             let capital = t.headers.get(&1).unwrap().to_lowercase().contains("exonym");
             let country_endonym = t.headers.get(&2).unwrap().to_lowercase().contains("endonym");
+            let capital_endonym = t.headers.get(&3).unwrap().to_lowercase().contains("endonym");
             let lang = t.headers.get(&4).unwrap().to_lowercase().contains("language");
 
-            if ! country || ! capital || ! country_endonym || ! lang {
+            if ! country || ! capital || ! country_endonym || ! capital_endonym || ! lang {
                 println!("Skipping table with headers {:?} as it does not seem \
                     familiar", t.headers);
 
                 continue;
             }
 
-            'row: for r in t.rows {
-                let mut cs = Self::default();
-    
-                for (i, v) in r.into_iter() {
-                    // 0 index is the exonym ...or endonym... and 1 is the capital
-                    // if those are missing skip the row.
-                    if [0, 1].contains(&i) && v.is_empty() {
-                        eprintln!("Skip adding endonyms for country {:?} as index {i} \
-                            was empty", cs);
-                        continue 'row;
-                    }
-
-                    // We're expecting lists for all values except country and
-                    // capital
-                    match i {
-                        0 => cs.exonym = v[0].trim_end().to_owned(),
-                        1 => cs.capital = v[0].trim_end().to_owned(),
-                        2 => cs.endonyms = v.into_iter().map(|s|s.trim_end().to_string()).collect(),
-                        4 => cs.languages = v.into_iter().map(|s|s.trim_end().to_string()).collect(),
-                        _ => bail!("Stupid developer with mixed indexes {}", url),
-                    }
+            familiar.push(t);
+        }
+
+        if familiar.is_empty() {
+            return Err(Error::TableNotFound {
+                url: source.to_string(), what: "endonyms and languages".into()
+            })
+        }
+
+        Ok(familiar)
+    }
+
+    pub fn from_tables(tables: &[Table]) -> Result<Vec<Self>> {
+        let mut scraped = vec![];
+
+        for t in tables {
+            // Country and capital headers both read exonym (and endonym), tell them apart
+            let mut t = t.to_owned();
+            t.rename_headers(&[(0, "exonym"), (1, "capital"), (2, "endonyms"),
+                (3, "capital_endonyms"), (4, "languages")]);
+
+            // Exonym ...or endonym... and the capital are required, skip rows
+            // missing either.
+            for r in t.deserialize_rows() {
+                match r {
+                    Ok(v) => scraped.push(v),
+                    Err(e) => eprintln!("Skip adding endonyms: {}", e),
+                }
+            }
+        }
+
+        Ok(scraped)
+    }
+}
+
+#[derive(Deserialize, Default, Debug)]
+pub struct WikipediaScrapedOfficialLanguages {
+    pub exonym: String,
+    #[serde(default)]
+    pub official: Vec<String>,
+    #[serde(default)]
+    pub regional: Vec<String>,
+    #[serde(default)]
+    pub minority: Vec<String>,
+    #[serde(default)]
+    pub national: Vec<String>,
+    #[serde(default)]
+    pub widely_spoken: Vec<String>,
+}
+
+impl WikipediaScrapedOfficialLanguages {
+    pub fn scrape(url: &str) -> Result<Vec<Self>> {
+        Self::scrape_source(Source::Url(url))
+    }
+
+    pub fn scrape_source(source: Source<'_>) -> Result<Vec<Self>> {
+        Self::from_tables(&Self::tables(source)?)
+    }
+
+    /// Official languages by country tables as found from the source, before
+    /// reading them
+    pub fn tables(source: Source<'_>) -> Result<Vec<Table>> {
+        println!("Scrape official languages data from wikipedia...");
+
+        // Define takes and discards
+        let columns = 6;
+        let discard_col_indexes = vec![];
+
+        let tables = super::source_table_scraper(source, columns, discard_col_indexes, vec![], language_list_parser)?;
+        let mut familiar = vec![];
+
+        for t in tables {
+            // Let's try to determine if this is our table by reading the headers
+            let country = t.headers.get(&0).unwrap().to_lowercase().contains("country");
+            let official = t.headers.get(&1).unwrap().to_lowercase().contains("official");
+            let spoken = t.headers.get(&5).unwrap().to_lowercase().contains("spoken");
+
+            if ! country || ! official || ! spoken {
+                println!("Skipping table with headers {:?} as it does not seem \
+                    familiar", t.headers);
+
+                continue;
+            }
+
+            familiar.push(t);
+        }
+
+        if familiar.is_empty() {
+            return Err(Error::TableNotFound {
+                url: source.to_string(), what: "official languages".into()
+            })
+        }
+
+        Ok(familiar)
+    }
+
+    pub fn from_tables(tables: &[Table]) -> Result<Vec<Self>> {
+        let mut scraped = vec![];
+
+        for t in tables {
+            // Language headers are worded in plural or singular, depending
+            let mut t = t.to_owned();
+            t.rename_headers(&[(0, "exonym"), (1, "official"), (2, "regional"),
+                (3, "minority"), (4, "national"), (5, "widely_spoken")]);
+
+            for r in t.deserialize_rows() {
+                match r {
+                    Ok(v) => scraped.push(v),
+                    Err(e) => eprintln!("Skip adding official languages: {}", e),
                 }
-                scraped.push(cs);
             }
         }
 
@@ -361,6 +383,40 @@ impl WikipediaScrapedEndonyms {
     }
 }
 
+/// Exactly one table is expected to be found
+fn single_table(mut tables: Vec<Table>, source: &Source<'_>, what: &str) -> Result<Table> {
+    match tables.len() {
+        0 => Err(Error::TableNotFound { url: source.to_string(), what: what.to_string() }),
+        1 => Ok(tables.remove(0)),
+        n => Err(Error::AmbiguousTables { url: source.to_string(), what: what.to_string(), found: n }),
+    }
+}
+
+fn deserialize_tables<T: DeserializeOwned>(tables: &[Table]) -> Result<Vec<T>> {
+    let mut scraped = vec![];
+
+    for t in tables {
+        scraped.extend(t.deserialize()?);
+    }
+
+    Ok(scraped)
+}
+
+/// Rows that fail to deserialize are reported and skipped
+fn deserialize_valid_rows<T: DeserializeOwned>(tables: &[Table], what: &str) -> Vec<T> {
+    let mut scraped = vec![];
+
+    for t in tables {
+        for r in t.deserialize_rows() {
+            match r {
+                Ok(v) => scraped.push(v),
+                Err(e) => eprintln!("Skip adding {}: {}", what, e),
+            }
+        }
+    }
+
+    scraped
+}
 
 fn common_parser(c: ElementRef<'_>, i: usize) -> Option<Vec<&str>> {
     let a = Selector::parse("a").unwrap();
@@ -379,7 +435,7 @@ fn common_parser(c: ElementRef<'_>, i: usize) -> Option<Vec<&str>> {
 
 fn endonym_lang_parser(c: ElementRef<'_>, i: usize) -> Option<Vec<&str>> {
     let a = Selector::parse("a").unwrap();
-    let span = Selector::parse("span").unwrap();
+    let names = Selector::parse("span, i").unwrap();
     let mut items = vec![];
 
     match i {
@@ -392,24 +448,43 @@ fn endonym_lang_parser(c: ElementRef<'_>, i: usize) -> Option<Vec<&str>> {
                 None => c.text().collect::<Vec<_>>()
             }
         },
-        2 => {
-            for l in c.select(&span) {
+        // Endonyms as language tag and name pairs, tag is either on the element
+        // holding the name or on one wrapping it. The closest one wins.
+        2 | 3 => {
+            let mut pairs = vec![];
+
+            for l in c.select(&names) {
                 let children = l.text().collect::<Vec<_>>();
-        
-                if children.len() == 1 {
-                    items.extend(children);
+
+                if children.len() != 1 || children[0].trim().is_empty() {
+                    continue;
+                }
+
+                // Name is held by an element inside this one, that one pairs it
+                if l.descendants().skip(1).filter_map(ElementRef::wrap).any(|e|names.matches(&e)) {
+                    continue;
                 }
+
+                let lang = l.ancestors()
+                    .take_while(|n|n.id() != c.id())
+                    .filter_map(|n|n.value().as_element())
+                    .chain([c.value()])
+                    .fold(l.value().attr("lang"), |lang, e|lang.or(e.attr("lang")))
+                    .unwrap_or("und");
+
+                pairs.push((lang, children[0].trim()));
             }
+
+            pairs.sort();
+            pairs.dedup();
+
+            return Some(pairs.into_iter().flat_map(|(l, n)|[l, n]).collect())
         },
+        // Languages along with the scripts they're written in, language registry
+        // tells them apart
         4 => {
-            'lang: for l in c.select(&a) {
+            for l in c.select(&a) {
                 if let Some(i) = l.text().next() {
-                    let excl = ["script", "alphabet", "characters"];
-
-                    for x in excl {
-                        if i.ends_with(x) { continue 'lang; }
-                    }
-
                     items.push(i);
                 }
             }
@@ -417,8 +492,171 @@ fn endonym_lang_parser(c: ElementRef<'_>, i: usize) -> Option<Vec<&str>> {
         _ => (),
     };
 
-    items.sort();
-    items.dedup();
+    // Languages are listed the most prominent first, keep the order
+    let mut unique = vec![];
+
+    for i in items {
+        if ! unique.contains(&i) {
+            unique.push(i);
+        }
+    }
+
+    Some(unique)
+}
+
+fn language_list_parser(c: ElementRef<'_>, i: usize) -> Option<Vec<&str>> {
+    let a = Selector::parse("a").unwrap();
+
+    if i == 0 {
+        return common_parser(c, i)
+    }
+
+    // Links to languages, footnote links left out
+    let mut items = c.select(&a)
+        .filter(|l|! l.ancestors().filter_map(|n|n.value().as_element())
+            .any(|e|e.name() == "sup"))
+        .filter_map(|l|l.text().next())
+        .map(|s|s.trim())
+        .filter(|s|! s.is_empty() && ! s.starts_with('['))
+        .collect::<Vec<_>>();
+
+    // Some cells list the languages as plain text
+    if items.is_empty() {
+        items = c.text()
+            .flat_map(|s|s.split(&[',', ';']))
+            .map(|s|s.trim())
+            .filter(|s|! s.starts_with('[') && s.chars().any(char::is_alphabetic))
+            .filter(|s|! s.eq_ignore_ascii_case("none"))
+            .collect();
+    }
 
     Some(items)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn countries(rows: &[[&[&str]; 6]]) -> Table {
+        let rows = rows.iter().map(|r|&r[..]).collect::<Vec<_>>();
+
+        Table::of_cells(&["ISO 3166 name", "Official state name", "Alpha-2 code", "Alpha-3 code",
+            "Numeric code", "Internet ccTLD"], &rows)
+    }
+
+    #[test]
+    fn keeps_countries_with_malformed_optional_values() {
+        let table = countries(&[
+            [&["Finland"], &["Republic of Finland"], &["FI"], &["FIN"], &["246"], &[".fi"]],
+            [&["Kosovo"], &["Republic of Kosovo"], &["XK"], &["—"], &["—"], &[]],
+            [&["Atlantis"], &["Atlantis"], &["AT"], &["A1T"], &["999"], &[".a_t"]],
+        ]);
+
+        let scraped = WikipediaScrapedCountry::from_tables(&[table]).unwrap();
+
+        assert_eq!(scraped.len(), 3);
+        assert_eq!(scraped[0].iso_3166_1_a3, Some(Alpha3::new("FIN").unwrap()));
+        assert_eq!(scraped[0].iso_3166_1_numeric, Some(246));
+        assert_eq!(scraped[0].tld, Some(Tld::new(".fi").unwrap()));
+
+        assert_eq!(scraped[1].iso_3166_1_a2, "XK");
+        assert_eq!(scraped[1].iso_3166_1_a3, None);
+        assert_eq!(scraped[1].iso_3166_1_numeric, None);
+        assert_eq!(scraped[1].tld, None);
+
+        assert_eq!(scraped[2].iso_3166_1_a3, None);
+        assert_eq!(scraped[2].tld, None);
+    }
+
+    #[test]
+    fn skips_countries_without_alpha2_code() {
+        let table = countries(&[
+            [&["Finland"], &["Republic of Finland"], &["FI"], &["FIN"], &["246"], &[".fi"]],
+            [&["Nowhere"], &["Nowhere"], &["—"], &["NWH"], &["001"], &[".nw"]],
+            [&["Sweden"], &["Kingdom of Sweden"], &["SE"], &["SWE"], &["752"], &[".se"]],
+        ]);
+
+        let scraped = WikipediaScrapedCountry::from_tables(&[table]).unwrap();
+        let codes = scraped.iter().map(|c|c.iso_3166_1_a2.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(codes, vec!["FI", "SE"]);
+    }
+
+    const ENDONYMS: &str = r#"<html><body>
+        <h2>E</h2>
+        <table class="wikitable">
+            <tr><th>Country (exonym)</th><th>Capital (exonym)</th><th>Country (endonym)</th>
+                <th>Capital (endonym)</th><th>Official or native language(s)</th></tr>
+            <tr>
+                <td><a href="/wiki/Egypt" title="Egypt">Egypt</a></td>
+                <td><a href="/wiki/Cairo" title="Cairo">Cairo</a></td>
+                <td><span lang="ar">مصر</span><br><i><span lang="ar-Latn">Miṣr</span></i></td>
+                <td lang="ar"><span>القاهرة</span><br>
+                    <span lang="ar"><i lang="ar-Latn">Al-Qāhirah</i></span></td>
+                <td><a href="/wiki/Arabic">Arabic</a></td>
+            </tr>
+            <tr>
+                <td><a href="/wiki/Eritrea" title="Eritrea">Eritrea</a></td>
+                <td><a href="/wiki/Asmara" title="Asmara">Asmara</a></td>
+                <td><span lang="ti">ኤርትራ</span></td>
+                <td><span lang="ti">ኣስመራ</span></td>
+                <td><a href="/wiki/Tigrinya">Tigrinya</a><br><a href="/wiki/Arabic">Arabic</a><br>
+                    <a href="/wiki/English">English</a><br><a href="/wiki/Tigrinya">Tigrinya</a></td>
+            </tr>
+        </table>
+        <h2>F</h2>
+        <table class="wikitable">
+            <tr><th>Country (exonym)</th><th>Capital (exonym)</th><th>Country (endonym)</th>
+                <th>Capital (endonym)</th><th>Official or native language(s)</th></tr>
+            <tr>
+                <td><a href="/wiki/Finland" title="Finland">Finland</a></td>
+                <td><a href="/wiki/Helsinki" title="Helsinki">Helsinki</a></td>
+                <td><i lang="fi">Suomi</i><br><span lang="sv"><i>Finland</i></span></td>
+                <td><span lang="fi">Helsinki</span><br><span lang="sv">Helsingfors</span></td>
+                <td><a href="/wiki/Finnish">Finnish</a><br><a href="/wiki/Swedish">Swedish</a></td>
+            </tr>
+        </table>
+    </body></html>"#;
+
+    fn endonyms(e: &[Endonym]) -> Vec<(&str, Option<&str>, bool)> {
+        e.iter().map(|e|(e.name.as_str(), e.lang.as_deref(), e.romanized)).collect()
+    }
+
+    #[test]
+    fn pairs_endonyms_with_their_language() {
+        let scraped = WikipediaScrapedEndonyms::scrape_source(Source::Html(ENDONYMS)).unwrap();
+
+        assert_eq!(scraped.len(), 3);
+        assert_eq!(scraped[0].exonym, "Egypt");
+        assert_eq!(scraped[0].capital, "Cairo");
+
+        // Tag is on the name, on an element wrapping it or on the cell. The
+        // closest one wins.
+        assert_eq!(endonyms(&scraped[0].endonyms), vec![
+            ("Miṣr", Some("ar-Latn"), true), ("مصر", Some("ar"), false)
+        ]);
+        assert_eq!(endonyms(&scraped[0].capital_endonyms), vec![
+            ("Al-Qāhirah", Some("ar-Latn"), true), ("القاهرة", Some("ar"), false)
+        ]);
+
+        assert_eq!(endonyms(&scraped[2].endonyms), vec![
+            ("Finland", Some("sv"), false), ("Suomi", Some("fi"), false)
+        ]);
+        assert_eq!(endonyms(&scraped[2].capital_endonyms), vec![
+            ("Helsingfors", Some("sv"), false), ("Helsinki", Some("fi"), false)
+        ]);
+    }
+
+    #[test]
+    fn keeps_languages_in_the_order_of_the_page() {
+        let scraped = WikipediaScrapedEndonyms::scrape_source(Source::Html(ENDONYMS)).unwrap();
+
+        assert_eq!(scraped[1].languages, vec!["Tigrinya", "Arabic", "English"]);
+
+        let eritrea = scraped.into_iter().nth(1).unwrap();
+        let country = crate::Country::from_wikipedia_endonyms_langs(eritrea);
+        let names = country.languages.unwrap().into_iter().map(|l|l.name).collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["Tigrinya", "Arabic", "English"]);
+    }
+}
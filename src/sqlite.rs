@@ -0,0 +1,324 @@
+//! Countries as a SQLite database. Currencies and languages get tables of their
+//! own, countries refer to them through `country_currencies` and
+//! `country_languages`. Everything else that a country has many of goes to a
+//! table keyed by the alpha-2 code.
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::{remove_file, rename};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use rusqlite::{params, Connection, Transaction};
+
+use crate::{Country, Currency, Language};
+use crate::error::{Error, Result};
+
+pub const SCHEMA: &str = "
+CREATE TABLE countries (
+    alpha2 TEXT PRIMARY KEY,
+    alpha3 TEXT,
+    numeric INTEGER,
+    exonym TEXT NOT NULL,
+    name TEXT NOT NULL,
+    capital TEXT,
+    tld TEXT,
+    flag TEXT,
+    calling_code TEXT
+);
+CREATE TABLE currencies (
+    id INTEGER PRIMARY KEY,
+    iso_4217 TEXT,
+    numeric_code INTEGER,
+    name TEXT NOT NULL,
+    minor_unit INTEGER,
+    symbol TEXT,
+    fraction TEXT,
+    fractions_in_unit INTEGER
+);
+CREATE TABLE country_currencies (
+    country TEXT NOT NULL REFERENCES countries(alpha2),
+    currency INTEGER NOT NULL REFERENCES currencies(id),
+    current INTEGER NOT NULL,
+    withdrawn TEXT
+);
+CREATE TABLE currency_symbols (
+    currency INTEGER NOT NULL REFERENCES currencies(id),
+    symbol TEXT NOT NULL,
+    kind TEXT NOT NULL
+);
+CREATE TABLE languages (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    iso_639_1 TEXT,
+    iso_639_3 TEXT
+);
+CREATE TABLE country_languages (
+    country TEXT NOT NULL REFERENCES countries(alpha2),
+    language INTEGER NOT NULL REFERENCES languages(id),
+    status TEXT,
+    position INTEGER NOT NULL
+);
+CREATE TABLE time_zones (
+    country TEXT NOT NULL REFERENCES countries(alpha2),
+    zone TEXT NOT NULL,
+    dst TEXT
+);
+CREATE TABLE names (
+    country TEXT NOT NULL REFERENCES countries(alpha2),
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    lang TEXT,
+    script TEXT,
+    romanized INTEGER NOT NULL
+);
+CREATE TABLE scripts (
+    country TEXT NOT NULL REFERENCES countries(alpha2),
+    code TEXT NOT NULL,
+    name TEXT NOT NULL,
+    rtl INTEGER NOT NULL
+);
+CREATE TABLE locales (
+    country TEXT NOT NULL REFERENCES countries(alpha2),
+    locale TEXT NOT NULL,
+    position INTEGER NOT NULL
+);
+CREATE INDEX countries_alpha3 ON countries(alpha3);
+CREATE INDEX countries_numeric ON countries(numeric);
+CREATE INDEX countries_tld ON countries(tld);
+CREATE INDEX countries_calling_code ON countries(calling_code);
+CREATE INDEX currencies_iso_4217 ON currencies(iso_4217);
+CREATE INDEX currencies_numeric_code ON currencies(numeric_code);
+CREATE INDEX country_currencies_country ON country_currencies(country);
+CREATE INDEX country_currencies_currency ON country_currencies(currency);
+CREATE INDEX languages_iso_639_1 ON languages(iso_639_1);
+CREATE INDEX languages_iso_639_3 ON languages(iso_639_3);
+CREATE INDEX country_languages_country ON country_languages(country);
+CREATE INDEX country_languages_language ON country_languages(language);
+CREATE INDEX time_zones_country ON time_zones(country);
+CREATE INDEX names_country ON names(country);
+CREATE INDEX names_name ON names(name COLLATE NOCASE);
+CREATE INDEX scripts_country ON scripts(country);
+CREATE INDEX scripts_code ON scripts(code);
+CREATE INDEX locales_country ON locales(country);
+CREATE INDEX locales_locale ON locales(locale);
+";
+
+/// Write the countries into a new database at the path, replacing the file if
+/// there is one. The database is written next to it first and moved in place
+/// when complete, a failed export leaves the old file as it was.
+pub fn export<K: Display>(countries: &BTreeMap<K, Country>, path: &Path) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    let tmp = PathBuf::from(tmp);
+
+    // Left over by an export that was cut short
+    match remove_file(&tmp) {
+        Err(e) if e.kind() != ErrorKind::NotFound =>
+            return Err(Error::File { path: tmp, source: e }),
+        _ => (),
+    }
+
+    let written = Connection::open(&tmp).and_then(|mut conn|write(&mut conn, countries));
+
+    if let Err(e) = written {
+        let _ = remove_file(&tmp);
+
+        return Err(Error::Sqlite { path: path.display().to_string(), source: e })
+    }
+
+    rename(&tmp, path).map_err(|e| Error::File { path: path.to_owned(), source: e })
+}
+
+/// Create the tables and insert the countries on an open database
+pub fn write<K: Display>(conn: &mut Connection, countries: &BTreeMap<K, Country>)
+-> rusqlite::Result<()> {
+    let tx = conn.transaction()?;
+
+    tx.execute_batch(SCHEMA)?;
+
+    let mut currencies = BTreeMap::<String, i64>::new();
+    let mut languages = BTreeMap::<String, i64>::new();
+
+    for c in countries.values() {
+        let alpha2 = &c.iso_3166_1_a2;
+
+        tx.execute("INSERT INTO countries (alpha2, alpha3, numeric, exonym, name, capital, tld, \
+            flag, calling_code) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)", params![
+            alpha2, c.iso_3166_1_a3.as_ref().map(|s|s.as_str()), c.iso_3166_1_numeric,
+            c.exonym, c.name, c.capital, c.tld.as_ref().map(|s|s.as_str()),
+            c.flag.as_ref().map(|s|s.as_str()), c.calling_code.as_ref().map(|s|s.as_str())
+        ])?;
+
+        for (i, x) in c.currencies().into_iter().enumerate() {
+            let id = currency_id(&tx, &mut currencies, x)?;
+
+            tx.execute("INSERT INTO country_currencies (country, currency, current, withdrawn) \
+                VALUES (?1, ?2, ?3, ?4)", params![
+                alpha2, id, i == 0 && c.currency.is_some(), x.withdrawn
+            ])?;
+        }
+
+        for (i, l) in c.languages.iter().flatten().enumerate() {
+            let id = language_id(&tx, &mut languages, l)?;
+            let status = l.status.and_then(|s|serde_json::to_value(s).ok())
+                .and_then(|v|v.as_str().map(|s|s.to_string()));
+
+            tx.execute("INSERT INTO country_languages (country, language, status, position) \
+                VALUES (?1, ?2, ?3, ?4)", params![alpha2, id, status, i])?;
+        }
+
+        if let Some(ref t) = c.time {
+            tx.execute("INSERT INTO time_zones (country, zone, dst) VALUES (?1, ?2, ?3)",
+                params![alpha2, t.zone, t.dst])?;
+        }
+
+        let mut names = vec![(c.exonym.as_str(), "exonym", None, None, false),
+            (c.name.as_str(), "name", None, None, false)];
+
+        names.extend(c.aliases.iter().flatten().map(|s|(s.as_str(), "alias", None, None, false)));
+        names.extend(c.endonyms.iter().flatten().map(|e|(e.name.as_str(), "endonym",
+            e.lang.as_deref(), e.script.as_deref(), e.romanized)));
+        names.extend(c.capital_endonyms.iter().flatten().map(|e|(e.name.as_str(),
+            "capital_endonym", e.lang.as_deref(), e.script.as_deref(), e.romanized)));
+
+        for (name, kind, lang, script, romanized) in names.into_iter().filter(|n|! n.0.is_empty()) {
+            tx.execute("INSERT INTO names (country, name, kind, lang, script, romanized) \
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)", params![alpha2, name, kind, lang, script, romanized])?;
+        }
+
+        for s in c.scripts.iter().flatten() {
+            tx.execute("INSERT INTO scripts (country, code, name, rtl) VALUES (?1, ?2, ?3, ?4)",
+                params![alpha2, s.code, s.name, s.rtl])?;
+        }
+
+        for (i, l) in c.locales.iter().flatten().enumerate() {
+            tx.execute("INSERT INTO locales (country, locale, position) VALUES (?1, ?2, ?3)",
+                params![alpha2, l, i])?;
+        }
+    }
+
+    tx.commit()
+}
+
+/// Currencies are shared by their ISO 4217 code, or by name if they have none.
+/// First country using the currency gets to describe it.
+fn currency_id(tx: &Transaction<'_>, ids: &mut BTreeMap<String, i64>, c: &Currency)
+-> rusqlite::Result<i64> {
+    let key = c.iso_4217.to_owned().unwrap_or(c.name.to_owned());
+
+    if let Some(id) = ids.get(&key) {
+        return Ok(*id)
+    }
+
+    tx.execute("INSERT INTO currencies (iso_4217, numeric_code, name, minor_unit, symbol, \
+        fraction, fractions_in_unit) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)", params![
+        c.iso_4217, c.numeric_code, c.name, c.minor_unit, c.symbol, c.fraction, c.fractions_in_unit
+    ])?;
+
+    let id = tx.last_insert_rowid();
+
+    for s in c.symbols.iter().flatten() {
+        let kind = serde_json::to_value(s.kind).ok()
+            .and_then(|v|v.as_str().map(|s|s.to_string()));
+
+        tx.execute("INSERT INTO currency_symbols (currency, symbol, kind) VALUES (?1, ?2, ?3)",
+            params![id, s.symbol, kind])?;
+    }
+
+    ids.insert(key, id);
+
+    Ok(id)
+}
+
+/// Languages are shared by their ISO 639-3 code, or by name if they have none
+fn language_id(tx: &Transaction<'_>, ids: &mut BTreeMap<String, i64>, l: &Language)
+-> rusqlite::Result<i64> {
+    let key = l.iso_639_3.to_owned().unwrap_or(l.name.to_owned());
+
+    if let Some(id) = ids.get(&key) {
+        return Ok(*id)
+    }
+
+    tx.execute("INSERT INTO languages (name, iso_639_1, iso_639_3) VALUES (?1, ?2, ?3)",
+        params![l.name, l.iso_639_1, l.iso_639_3])?;
+
+    let id = tx.last_insert_rowid();
+    ids.insert(key, id);
+
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn countries() -> BTreeMap<String, Country> {
+        let mut all = serde_json::from_str::<BTreeMap<String, Country>>(include_str!("../out.json"))
+            .unwrap();
+        all.retain(|k, _|["AX", "FI", "HR"].contains(&k.as_str()));
+        all
+    }
+
+    fn count(conn: &Connection, table: &str) -> usize {
+        conn.query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |r|r.get(0)).unwrap()
+    }
+
+    #[test]
+    fn writes_rows_of_each_country() {
+        let countries = countries();
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys = ON").unwrap();
+
+        write(&mut conn, &countries).unwrap();
+
+        let sum = |f: fn(&Country) -> usize|countries.values().map(f).sum::<usize>();
+
+        assert_eq!(count(&conn, "countries"), 3);
+        // Euro is shared, kuna is a currency of its own
+        assert_eq!(count(&conn, "currencies"), 2);
+        assert_eq!(count(&conn, "country_currencies"), 4);
+        assert_eq!(count(&conn, "country_languages"),
+            sum(|c|c.languages.as_ref().map_or(0, |v|v.len())));
+        assert_eq!(count(&conn, "locales"), sum(|c|c.locales.as_ref().map_or(0, |v|v.len())));
+        assert_eq!(count(&conn, "scripts"), sum(|c|c.scripts.as_ref().map_or(0, |v|v.len())));
+        assert_eq!(count(&conn, "time_zones"), sum(|c|c.time.iter().count()));
+
+        let current = conn.query_row("SELECT x.iso_4217 FROM country_currencies cc \
+            JOIN currencies x ON x.id = cc.currency WHERE cc.country = 'HR' AND cc.current",
+            [], |r|r.get::<_, String>(0)).unwrap();
+
+        assert_eq!(current, "EUR");
+    }
+
+    #[test]
+    fn rows_refer_to_existing_keys() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("PRAGMA foreign_keys = ON").unwrap();
+
+        write(&mut conn, &countries()).unwrap();
+
+        let violations = conn.prepare("PRAGMA foreign_key_check").unwrap()
+            .query_map([], |r|r.get::<_, String>(0)).unwrap()
+            .count();
+
+        assert_eq!(violations, 0);
+
+        // Keys are enforced, rows of unknown countries are refused
+        assert!(conn.execute("INSERT INTO locales (country, locale, position) \
+            VALUES ('XX', 'xx-XX', 0)", []).is_err());
+    }
+
+    #[test]
+    fn export_replaces_the_file() {
+        let path = std::env::temp_dir().join(format!("bubbles-{}.sqlite", std::process::id()));
+        std::fs::write(&path, "not a database").unwrap();
+
+        export(&countries(), &path).unwrap();
+
+        let conn = Connection::open(&path).unwrap();
+
+        assert_eq!(count(&conn, "countries"), 3);
+        assert!(! Path::new(&format!("{}.tmp", path.display())).exists());
+
+        remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,233 @@
+//! Values of the country that have a strict shape. They're checked when created
+//! and when read from JSON, so a country can't hold a malformed one.
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+use crate::CountryCodeA3;
+use crate::error::{Error, Result};
+
+fn invalid(kind: &str, value: &str, message: &str) -> Error {
+    Error::InvalidValue { kind: kind.into(), value: value.to_string(), message: message.into() }
+}
+
+/// ISO 3166-1 alpha-3 code of a country, `FIN`. Unlike `CountryCodeA3` it takes
+/// codes newer than the generated enums too.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct Alpha3(String);
+
+impl Alpha3 {
+    /// Letters are uppercased, anything else than three of them is refused
+    pub fn new(s: &str) -> Result<Self> {
+        let s = s.trim().to_ascii_uppercase();
+
+        if s.len() != 3 || ! s.chars().all(|c|c.is_ascii_uppercase()) {
+            return Err(invalid("alpha-3 code", &s, "not three letters"))
+        }
+
+        Ok(Self(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn code(&self) -> Option<CountryCodeA3> {
+        self.0.parse().ok()
+    }
+}
+
+/// Country code top level domain with the leading dot, `.fi`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct Tld(String);
+
+impl Tld {
+    /// Domain is lowercased, the dot added if it's missing. Internationalized
+    /// domains are taken in their punycode (`.xn--p1ai`) form.
+    pub fn new(s: &str) -> Result<Self> {
+        let s = format!(".{}", s.trim().trim_start_matches('.').to_lowercase());
+        let label = &s[1..];
+
+        if label.len() < 2 || label.len() > 63 {
+            return Err(invalid("top level domain", &s, "not 2 to 63 characters long"))
+        }
+
+        if ! label.chars().all(|c|c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        || label.starts_with('-') || label.ends_with('-') {
+            return Err(invalid("top level domain", &s, "not a domain label"))
+        }
+
+        Ok(Self(s))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// International calling code with the area codes of the country when it shares
+/// the code with others, `358` or `1 (809, 829, 849)`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct CallingCode(String);
+
+impl CallingCode {
+    /// Leading `+` is dropped
+    pub fn new(s: &str) -> Result<Self> {
+        let s = s.trim().trim_start_matches('+').trim();
+        let digits = |s: &str|! s.is_empty() && s.chars().all(|c|c.is_ascii_digit());
+
+        let (code, areas) = match s.split_once(' ') {
+            Some((c, a)) => (c, Some(a)),
+            None => (s, None),
+        };
+
+        if ! digits(code) || code.len() > 3 {
+            return Err(invalid("calling code", s, "not one to three digits"))
+        }
+
+        if let Some(a) = areas {
+            let areas = a.strip_prefix('(').and_then(|a|a.strip_suffix(')'));
+
+            if ! areas.is_some_and(|a|a.split(", ").all(digits)) {
+                return Err(invalid("calling code", s, "area codes are not a list of numbers \
+                    in brackets"))
+            }
+        }
+
+        Ok(Self(s.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The country code without area codes, `1`
+    pub fn code(&self) -> &str {
+        self.0.split(' ').next().unwrap_or_default()
+    }
+
+    pub fn area_codes(&self) -> Vec<&str> {
+        match self.0.split_once(" (") {
+            Some((_, a)) => a.trim_end_matches(')').split(", ").collect(),
+            None => vec![],
+        }
+    }
+}
+
+/// Flag emoji of the country, a pair of regional indicator symbols
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct Flag(String);
+
+impl Flag {
+    pub fn new(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let indicators = s.chars().filter(|c|('\u{1F1E6}'..='\u{1F1FF}').contains(c)).count();
+
+        if indicators != 2 || s.chars().count() != 2 {
+            return Err(invalid("flag", s, "not two regional indicator symbols"))
+        }
+
+        Ok(Self(s.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Alpha-2 code the regional indicators spell, `FI` of 🇫🇮
+    pub fn alpha2(&self) -> String {
+        self.0.chars()
+            .filter_map(|c|char::from_u32(c as u32 - 0x1F1E6 + 'A' as u32))
+            .collect()
+    }
+}
+
+macro_rules! string_type {
+    ($($t:ident),*) => {$(
+        impl FromStr for $t {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                Self::new(s)
+            }
+        }
+
+        impl TryFrom<String> for $t {
+            type Error = Error;
+
+            fn try_from(s: String) -> Result<Self> {
+                Self::new(&s)
+            }
+        }
+
+        impl From<$t> for String {
+            fn from(v: $t) -> Self {
+                v.0
+            }
+        }
+
+        impl AsRef<str> for $t {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $t {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    )*};
+}
+
+string_type!(Alpha3, Tld, CallingCode, Flag);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha3_codes_are_three_letters() {
+        assert_eq!(Alpha3::new(" fin ").unwrap().as_str(), "FIN");
+        assert!(Alpha3::new("FI").is_err());
+        assert!(Alpha3::new("F1N").is_err());
+    }
+
+    #[test]
+    fn tlds_are_domain_labels() {
+        assert_eq!(Tld::new("FI").unwrap().as_str(), ".fi");
+        assert_eq!(Tld::new(".xn--p1ai").unwrap().as_str(), ".xn--p1ai");
+        assert!(Tld::new(".f").is_err());
+        assert!(Tld::new(".a_t").is_err());
+        assert!(Tld::new(".-fi").is_err());
+    }
+
+    #[test]
+    fn calling_codes_take_area_codes() {
+        let c = CallingCode::new("+1 (809, 829, 849)").unwrap();
+
+        assert_eq!(c.code(), "1");
+        assert_eq!(c.area_codes(), vec!["809", "829", "849"]);
+        assert!(CallingCode::new("3580").is_err());
+        assert!(CallingCode::new("1 809").is_err());
+        assert!(CallingCode::new("").is_err());
+    }
+
+    #[test]
+    fn flags_spell_alpha2_codes() {
+        assert_eq!(Flag::new("🇫🇮").unwrap().alpha2(), "FI");
+        assert!(Flag::new("🇫").is_err());
+        assert!(Flag::new("FI").is_err());
+    }
+
+    #[test]
+    fn malformed_values_are_refused_from_json() {
+        assert!(serde_json::from_str::<Tld>(r#"".fi""#).is_ok());
+        assert!(serde_json::from_str::<Tld>(r#""fi fi""#).is_err());
+        assert!(serde_json::from_str::<Flag>(r#""XX""#).is_err());
+    }
+}
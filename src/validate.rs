@@ -0,0 +1,321 @@
+//! Checks on how the fields of the built countries agree with each other
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+use crate::Country;
+use crate::currency::iso_4217;
+
+/// Countries whose top level domain is not (only) their alpha-2 code
+const TLD_EXCEPTIONS: &[(&str, &str)] = &[("GB", ".uk")];
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "info" => Ok(Self::Info),
+            "warning" => Ok(Self::Warning),
+            "error" => Ok(Self::Error),
+            x => Err(format!("Unknown severity {}, expected info, warning or error", x)),
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Info => write!(f, "info"),
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Rule {
+    /// Countries are keyed with their alpha-2 code
+    KeyMatchesCode,
+    /// Regional indicators of the flag spell the alpha-2 code
+    FlagMatchesCode,
+    /// Top level domain is the alpha-2 code, apart from the known exceptions
+    TldMatchesCode,
+    UniqueAlpha3,
+    UniqueNumeric,
+    /// Current currency is on the ISO 4217 list
+    KnownCurrency,
+}
+
+impl Rule {
+    pub const ALL: [Self; 6] = [Self::KeyMatchesCode, Self::FlagMatchesCode, Self::TldMatchesCode,
+        Self::UniqueAlpha3, Self::UniqueNumeric, Self::KnownCurrency];
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::KeyMatchesCode => Severity::Error,
+            Self::FlagMatchesCode => Severity::Error,
+            Self::TldMatchesCode => Severity::Warning,
+            Self::UniqueAlpha3 => Severity::Error,
+            Self::UniqueNumeric => Severity::Error,
+            Self::KnownCurrency => Severity::Warning,
+        }
+    }
+
+    /// Findings of the rule, one per country breaking it
+    pub fn check<K: Display>(&self, countries: &BTreeMap<K, Country>) -> Vec<Finding> {
+        let finding = |k: &str, m: String| Finding {
+            rule: *self, severity: self.severity(), country: k.to_string(), message: m
+        };
+
+        let countries = countries.iter()
+            .map(|(k, c)|(k.to_string(), c))
+            .collect::<BTreeMap<String, &Country>>();
+
+        match self {
+            Self::KeyMatchesCode => countries.iter()
+                .filter(|(k, c)|c.iso_3166_1_a2.ne(*k))
+                .map(|(k, c)|finding(k, format!("alpha-2 code is {}", c.iso_3166_1_a2)))
+                .collect(),
+            Self::FlagMatchesCode => countries.iter()
+                .filter_map(|(k, c)|c.flag.as_ref().map(|f|(k, f)))
+                .filter(|(k, f)|f.alpha2().ne(*k))
+                .map(|(k, f)|finding(k, format!("flag {} spells {}", f, f.alpha2())))
+                .collect(),
+            Self::TldMatchesCode => countries.iter()
+                .filter_map(|(k, c)|c.tld.as_ref().map(|t|(k, t)))
+                .filter(|(k, t)|t.as_str().ne(&format!(".{}", k.to_lowercase()))
+                    && ! TLD_EXCEPTIONS.contains(&(k.as_str(), t.as_str())))
+                .map(|(k, t)|finding(k, format!("top level domain {} is not the code", t)))
+                .collect(),
+            Self::UniqueAlpha3 => duplicates(&countries, |c|c.iso_3166_1_a3.as_ref()
+                .map(|s|s.to_string()))
+                .into_iter()
+                .map(|(k, v, o)|finding(k, format!("alpha-3 code {} is also the code of {}", v, o)))
+                .collect(),
+            Self::UniqueNumeric => duplicates(&countries, |c|c.iso_3166_1_numeric
+                .map(|n|format!("{:03}", n)))
+                .into_iter()
+                .map(|(k, v, o)|finding(k, format!("numeric code {} is also the code of {}", v, o)))
+                .collect(),
+            Self::KnownCurrency => countries.iter()
+                .filter_map(|(k, c)|c.currency.as_ref()?.iso_4217.as_ref().map(|s|(k, s)))
+                .filter(|(_, s)|! iso_4217().contains_key(*s))
+                .map(|(k, s)|finding(k, format!("currency {} is not on the ISO 4217 list", s)))
+                .collect(),
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::ALL.into_iter()
+            .find(|r|r.to_string().eq(&s.to_lowercase().replace('-', "_")))
+            .ok_or(format!("Unknown rule {}", s))
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::KeyMatchesCode => "key_matches_code",
+            Self::FlagMatchesCode => "flag_matches_code",
+            Self::TldMatchesCode => "tld_matches_code",
+            Self::UniqueAlpha3 => "unique_alpha3",
+            Self::UniqueNumeric => "unique_numeric",
+            Self::KnownCurrency => "known_currency",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// Country breaking a rule
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Finding {
+    pub rule: Rule,
+    pub severity: Severity,
+    pub country: String,
+    pub message: String,
+}
+
+impl Display for Finding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:<7} {} {}: {}", self.severity, self.country, self.rule, self.message)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone)]
+pub struct Report {
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    /// Check the countries against the rules, findings ordered by country
+    pub fn new<K: Display>(countries: &BTreeMap<K, Country>, rules: &[Rule]) -> Self {
+        let mut findings = rules.iter()
+            .flat_map(|r|r.check(countries))
+            .collect::<Vec<_>>();
+
+        findings.sort_by(|a, b|a.country.cmp(&b.country)
+            .then(b.severity.cmp(&a.severity))
+            .then(a.rule.cmp(&b.rule)));
+
+        Self { findings }
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings.iter().filter(|f|f.severity == severity).count()
+    }
+
+    /// Any finding as severe as the given one or more
+    pub fn fails(&self, severity: Severity) -> bool {
+        self.findings.iter().any(|f|f.severity >= severity)
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for i in self.findings.iter() {
+            writeln!(f, "{}", i)?;
+        }
+
+        write!(f, "{} errors, {} warnings, {} infos", self.count(Severity::Error),
+            self.count(Severity::Warning), self.count(Severity::Info))
+    }
+}
+
+/// Countries sharing the value with a country before them, with the value and
+/// the key of that earlier country
+fn duplicates<'a, F>(countries: &'a BTreeMap<String, &Country>, value: F) -> Vec<(&'a str, String, &'a str)>
+where F: Fn(&Country) -> Option<String> {
+    let mut seen = BTreeMap::<String, &str>::new();
+    let mut duplicates = vec![];
+
+    for (k, c) in countries.iter() {
+        let v = match value(c) {
+            Some(v) => v,
+            None => continue,
+        };
+
+        match seen.get(&v) {
+            Some(o) => duplicates.push((k.as_str(), v, *o)),
+            None => {
+                seen.insert(v, k);
+            },
+        }
+    }
+
+    duplicates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Alpha3, CountryCode, Currency, Flag, Tld};
+
+    fn country(a2: &str, a3: &str, numeric: u16, tld: &str, flag: &str, currency: &str) -> Country {
+        Country {
+            iso_3166_1_a2: a2.into(),
+            iso_3166_1_a3: Some(Alpha3::new(a3).unwrap()),
+            iso_3166_1_numeric: Some(numeric),
+            tld: Some(Tld::new(tld).unwrap()),
+            flag: Some(Flag::new(flag).unwrap()),
+            currency: Some(Currency { iso_4217: Some(currency.into()), ..Currency::default() }),
+            ..Country::default()
+        }
+    }
+
+    fn valid() -> BTreeMap<String, Country> {
+        BTreeMap::from([
+            ("FI".to_string(), country("FI", "FIN", 246, ".fi", "🇫🇮", "EUR")),
+            ("GB".to_string(), country("GB", "GBR", 826, ".uk", "🇬🇧", "GBP")),
+            ("SE".to_string(), country("SE", "SWE", 752, ".se", "🇸🇪", "SEK")),
+        ])
+    }
+
+    fn broken(countries: &BTreeMap<String, Country>, rule: Rule) -> Vec<String> {
+        rule.check(countries).into_iter().map(|f|f.country).collect()
+    }
+
+    #[test]
+    fn valid_countries_have_no_findings() {
+        let report = Report::new(&valid(), &Rule::ALL);
+
+        assert!(report.findings.is_empty());
+        assert!(! report.fails(Severity::Info));
+    }
+
+    #[test]
+    fn each_rule_finds_the_country_breaking_it() {
+        let mut countries = valid();
+        countries.insert("AX".into(), country("FI", "FIN", 246, ".ax", "🇫🇮", "XYZ"));
+
+        assert_eq!(broken(&countries, Rule::KeyMatchesCode), vec!["AX"]);
+        assert_eq!(broken(&countries, Rule::FlagMatchesCode), vec!["AX"]);
+        assert!(broken(&countries, Rule::TldMatchesCode).is_empty());
+        // Duplicates are reported on the later country in key order
+        assert_eq!(broken(&countries, Rule::UniqueAlpha3), vec!["FI"]);
+        assert_eq!(broken(&countries, Rule::UniqueNumeric), vec!["FI"]);
+        assert_eq!(broken(&countries, Rule::KnownCurrency), vec!["AX"]);
+
+        countries.insert("DE".into(), country("DE", "DEU", 276, ".uk", "🇩🇪", "EUR"));
+
+        assert_eq!(broken(&countries, Rule::TldMatchesCode), vec!["DE"]);
+    }
+
+    #[test]
+    fn reports_order_findings_by_country_and_severity() {
+        let mut countries = valid();
+        countries.insert("AX".into(), country("AX", "ALA", 248, ".fi", "🇦🇽", "XYZ"));
+        countries.insert("BX".into(), country("BX", "BXX", 1, ".bx", "🇫🇮", "EUR"));
+
+        let report = Report::new(&countries, &Rule::ALL);
+        let found = report.findings.iter()
+            .map(|f|(f.country.as_str(), f.rule))
+            .collect::<Vec<_>>();
+
+        assert_eq!(found, vec![
+            ("AX", Rule::TldMatchesCode),
+            ("AX", Rule::KnownCurrency),
+            ("BX", Rule::FlagMatchesCode),
+        ]);
+        assert_eq!(report.count(Severity::Warning), 2);
+        assert!(report.fails(Severity::Error));
+
+        let report = Report::new(&countries, &[Rule::KnownCurrency]);
+
+        assert!(report.fails(Severity::Warning));
+        assert!(! report.fails(Severity::Error));
+    }
+
+    #[test]
+    fn takes_countries_keyed_by_code_enums() {
+        let countries = BTreeMap::from([
+            (CountryCode::FI, country("SE", "FIN", 246, ".fi", "🇫🇮", "EUR")),
+        ]);
+
+        let found = Rule::KeyMatchesCode.check(&countries);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].country, "FI");
+    }
+
+    #[test]
+    fn rules_and_severities_read_from_strings() {
+        assert_eq!("unique-alpha3".parse::<Rule>(), Ok(Rule::UniqueAlpha3));
+        assert_eq!("Warning".parse::<Severity>(), Ok(Severity::Warning));
+        assert!("unique".parse::<Rule>().is_err());
+    }
+}